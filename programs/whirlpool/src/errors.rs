@@ -115,6 +115,20 @@ pub enum ErrorCode {
     BundledPositionAlreadyClosed, //0x179d
     #[msg("Unable to delete PositionBundle with open positions")]
     PositionBundleNotDeletable, //0x179e
+
+    #[msg("Amount mismatch between the output of hop one and the input of hop two")]
+    IntermediateTokenAmountMismatch, //0x179f
+
+    #[msg("Oracle cardinality is invalid or exceeds the maximum number of observations")]
+    InvalidOracleCardinality, //0x17a0
+    #[msg("Requested oracle observation is older than the oldest stored observation")]
+    OracleObservationTooOld, //0x17a1
+    #[msg("TickArray account for different whirlpool provided")]
+    DifferentWhirlpoolTickArrayAccount, //0x17a2
+    #[msg("Invalid hops or hop accounts provided for route swap")]
+    InvalidRouteSwapHops, //0x17a3
+    #[msg("Duplicate pool in route swap")]
+    DuplicateRouteSwapPool, //0x17a4
 }
 
 impl From<TryFromIntError> for ErrorCode {
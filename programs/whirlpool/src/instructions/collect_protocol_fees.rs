@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::state::*;
+use crate::util::transfer_from_vault_to_owner;
+
+#[derive(Accounts)]
+pub struct CollectProtocolFees<'info> {
+    pub whirlpools_config: Box<Account<'info, WhirlpoolsConfig>>,
+
+    #[account(mut, has_one = whirlpools_config)]
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    #[account(address = whirlpools_config.collect_protocol_fees_authority)]
+    pub collect_protocol_fees_authority: Signer<'info>,
+
+    #[account(mut, address = whirlpool.token_vault_a)]
+    pub token_vault_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = whirlpool.token_vault_b)]
+    pub token_vault_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, constraint = token_destination_a.mint == whirlpool.token_mint_a)]
+    pub token_destination_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, constraint = token_destination_b.mint == whirlpool.token_mint_b)]
+    pub token_destination_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+/// Collects the protocol fees accrued in the whirlpool. Only the config's collect protocol fees
+/// authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<CollectProtocolFees>) -> Result<()> {
+    let whirlpool = &ctx.accounts.whirlpool;
+
+    transfer_from_vault_to_owner(
+        whirlpool,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_destination_a,
+        &ctx.accounts.token_program,
+        whirlpool.protocol_fee_owed_a,
+    )?;
+
+    transfer_from_vault_to_owner(
+        whirlpool,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_destination_b,
+        &ctx.accounts.token_program,
+        whirlpool.protocol_fee_owed_b,
+    )?;
+
+    ctx.accounts.whirlpool.reset_protocol_fees_owed();
+    Ok(())
+}
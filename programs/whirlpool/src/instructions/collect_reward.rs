@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::util::{transfer_from_vault_to_owner, verify_position_authority};
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct CollectReward<'info> {
+    #[account(constraint = (reward_index as usize) < NUM_REWARDS @ ErrorCode::InvalidRewardIndex)]
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut,
+        constraint = reward_owner_account.mint == whirlpool.reward_infos[reward_index as usize].mint
+    )]
+    pub reward_owner_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = whirlpool.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+/// Collects the rewards owed to a position at the given reward index. Rewards are only accrued
+/// into the position by `update_fees_and_rewards` or by modifying its liquidity.
+pub fn handler(ctx: Context<CollectReward>, reward_index: u8) -> Result<()> {
+    verify_position_authority(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+    )?;
+
+    let index = reward_index as usize;
+    let position = &mut ctx.accounts.position;
+    let amount_owed = position.reward_infos[index].amount_owed;
+
+    transfer_from_vault_to_owner(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.reward_owner_account,
+        &ctx.accounts.token_program,
+        amount_owed,
+    )?;
+
+    position.update_reward_owed(index, 0);
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct IncreaseOracleCardinality<'info> {
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub oracle: AccountLoader<'info, Oracle>,
+}
+
+pub fn handler(
+    ctx: Context<IncreaseOracleCardinality>,
+    observation_cardinality_next: u16,
+) -> Result<()> {
+    ctx.accounts
+        .oracle
+        .load_mut()?
+        .grow(observation_cardinality_next)
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::util::to_timestamp_u64;
+
+#[derive(Accounts)]
+pub struct InitializeOracle<'info> {
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"oracle", whirlpool.key().as_ref()],
+      bump,
+      space = Oracle::LEN)]
+    pub oracle: AccountLoader<'info, Oracle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeOracle>) -> Result<()> {
+    let mut oracle = ctx.accounts.oracle.load_init()?;
+    let timestamp = to_timestamp_u64(Clock::get()?.unix_timestamp)?;
+    oracle.initialize(ctx.accounts.whirlpool.key(), timestamp);
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct InitializeReward<'info> {
+    #[account(
+        constraint = (reward_index as usize) < NUM_REWARDS @ ErrorCode::InvalidRewardIndex,
+        address = whirlpool.reward_infos[reward_index as usize].authority
+    )]
+    pub reward_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mut)]
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    #[account(init,
+      payer = funder,
+      token::mint = reward_mint,
+      token::authority = whirlpool)]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Initialize the reward at the given index with a new reward mint and vault. Rewards must be
+/// initialized in order, starting from the lowest uninitialized index.
+pub fn handler(ctx: Context<InitializeReward>, reward_index: u8) -> Result<()> {
+    let whirlpool = &mut ctx.accounts.whirlpool;
+
+    whirlpool.initialize_reward(
+        reward_index as usize,
+        ctx.accounts.reward_mint.key(),
+        ctx.accounts.reward_vault.key(),
+    )
+}
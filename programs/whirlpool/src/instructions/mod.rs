@@ -1,5 +1,73 @@
+pub mod close_bundled_position;
+pub mod close_position;
+pub mod collect_fees;
+pub mod collect_protocol_fees;
+pub mod collect_reward;
+pub mod decrease_liquidity;
+pub mod delete_position_bundle;
+pub mod increase_liquidity;
+pub mod increase_oracle_cardinality;
+pub mod initialize_config;
+pub mod initialize_fee_tier;
+pub mod initialize_oracle;
+pub mod initialize_pool;
+pub mod initialize_position_bundle;
+pub mod initialize_position_bundle_with_metadata;
+pub mod initialize_reward;
+pub mod initialize_tick_array;
+pub mod open_bundled_position;
+pub mod open_position;
+pub mod open_position_with_metadata;
+pub mod route_swap;
+pub mod set_collect_protocol_fees_authority;
+pub mod set_default_fee_rate;
+pub mod set_default_protocol_fee_rate;
+pub mod set_fee_authority;
+pub mod set_fee_rate;
+pub mod set_protocol_fee_rate;
+pub mod set_reward_authority;
+pub mod set_reward_authority_by_super_authority;
+pub mod set_reward_emissions;
+pub mod set_reward_emissions_super_authority;
 pub mod swap;
 pub mod two_hop_swap;
+pub mod update_fees_and_rewards;
 
+pub use close_bundled_position::*;
+pub use close_position::*;
+pub use collect_fees::*;
+pub use collect_protocol_fees::*;
+pub use collect_reward::*;
+pub use delete_position_bundle::*;
+pub use increase_liquidity::*;
+pub use increase_oracle_cardinality::*;
+pub use initialize_config::*;
+pub use initialize_fee_tier::*;
+pub use initialize_oracle::*;
+pub use initialize_pool::*;
+pub use initialize_position_bundle::*;
+pub use initialize_position_bundle_with_metadata::*;
+pub use initialize_reward::*;
+pub use initialize_tick_array::*;
+pub use open_bundled_position::*;
+pub use open_position::*;
+pub use open_position_with_metadata::*;
+pub(crate) use route_swap::__client_accounts_route_swap;
+#[cfg(feature = "cpi")]
+pub(crate) use route_swap::__cpi_client_accounts_route_swap;
+pub use route_swap::{
+    handler as route_swap_handler, RouteSwap, RouteSwapHop, ROUTE_SWAP_HOP_ACCOUNTS_LEN,
+};
+pub use set_collect_protocol_fees_authority::*;
+pub use set_default_fee_rate::*;
+pub use set_default_protocol_fee_rate::*;
+pub use set_fee_authority::*;
+pub use set_fee_rate::*;
+pub use set_protocol_fee_rate::*;
+pub use set_reward_authority::*;
+pub use set_reward_authority_by_super_authority::*;
+pub use set_reward_emissions::*;
+pub use set_reward_emissions_super_authority::*;
 pub use swap::*;
 pub use two_hop_swap::*;
+pub use update_fees_and_rewards::*;
@@ -0,0 +1,613 @@
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::{
+    errors::ErrorCode,
+    manager::swap_manager::*,
+    state::{TickArray, Whirlpool},
+    util::{to_timestamp_u64, update_and_swap_whirlpool, update_oracle, SwapTickSequence},
+};
+
+/// The number of remaining accounts that make up a single hop of a route swap.
+pub const ROUTE_SWAP_HOP_ACCOUNTS_LEN: usize = 9;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RouteSwapHop {
+    pub a_to_b: bool,
+    pub sqrt_price_limit: u128,
+    pub oracle_bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct RouteSwap<'info> {
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub token_authority: Signer<'info>,
+}
+
+// The accounts of a single hop, read from remaining accounts in the following order:
+// whirlpool, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b,
+// tick_array_0, tick_array_1, tick_array_2, oracle.
+// Each account is held to the same constraints as the corresponding account of `Swap`.
+struct RouteSwapHopAccounts<'info> {
+    whirlpool: Box<Account<'info, Whirlpool>>,
+    token_owner_account_a: Box<Account<'info, TokenAccount>>,
+    token_vault_a: Box<Account<'info, TokenAccount>>,
+    token_owner_account_b: Box<Account<'info, TokenAccount>>,
+    token_vault_b: Box<Account<'info, TokenAccount>>,
+    tick_array_0: AccountLoader<'info, TickArray>,
+    tick_array_1: AccountLoader<'info, TickArray>,
+    tick_array_2: AccountLoader<'info, TickArray>,
+    oracle: &'info AccountInfo<'info>,
+}
+
+impl<'info> RouteSwapHopAccounts<'info> {
+    fn try_from(accounts: &'info [AccountInfo<'info>], oracle_bump: u8) -> Result<Self> {
+        let whirlpool = Box::new(Account::<Whirlpool>::try_from(&accounts[0])?);
+        if !accounts[0].is_writable {
+            return Err(AnchorErrorCode::ConstraintMut.into());
+        }
+
+        let token_owner_account_a = Box::new(Account::<TokenAccount>::try_from(&accounts[1])?);
+        if token_owner_account_a.mint != whirlpool.token_mint_a {
+            return Err(AnchorErrorCode::ConstraintRaw.into());
+        }
+        let token_vault_a = Box::new(Account::<TokenAccount>::try_from(&accounts[2])?);
+        if token_vault_a.key() != whirlpool.token_vault_a {
+            return Err(AnchorErrorCode::ConstraintAddress.into());
+        }
+
+        let token_owner_account_b = Box::new(Account::<TokenAccount>::try_from(&accounts[3])?);
+        if token_owner_account_b.mint != whirlpool.token_mint_b {
+            return Err(AnchorErrorCode::ConstraintRaw.into());
+        }
+        let token_vault_b = Box::new(Account::<TokenAccount>::try_from(&accounts[4])?);
+        if token_vault_b.key() != whirlpool.token_vault_b {
+            return Err(AnchorErrorCode::ConstraintAddress.into());
+        }
+
+        if accounts[5..8]
+            .iter()
+            .any(|account_info| !account_info.is_writable)
+        {
+            return Err(AnchorErrorCode::ConstraintMut.into());
+        }
+        let tick_array_0 = load_hop_tick_array(&accounts[5], &whirlpool.key())?;
+        let tick_array_1 = load_hop_tick_array(&accounts[6], &whirlpool.key())?;
+        let tick_array_2 = load_hop_tick_array(&accounts[7], &whirlpool.key())?;
+
+        // The bump is provided by the caller to avoid searching for it on every hop
+        let oracle = &accounts[8];
+        let oracle_address = Pubkey::create_program_address(
+            &[b"oracle", whirlpool.key().as_ref(), &[oracle_bump]],
+            &crate::ID,
+        )
+        .map_err(|_| AnchorErrorCode::ConstraintSeeds)?;
+        if oracle.key() != oracle_address {
+            return Err(AnchorErrorCode::ConstraintSeeds.into());
+        }
+
+        Ok(Self {
+            whirlpool,
+            token_owner_account_a,
+            token_vault_a,
+            token_owner_account_b,
+            token_vault_b,
+            tick_array_0,
+            tick_array_1,
+            tick_array_2,
+            oracle,
+        })
+    }
+}
+
+fn load_hop_tick_array<'info>(
+    account_info: &'info AccountInfo<'info>,
+    whirlpool: &Pubkey,
+) -> Result<AccountLoader<'info, TickArray>> {
+    let tick_array = AccountLoader::<TickArray>::try_from(account_info)?;
+    if tick_array.load()?.whirlpool != *whirlpool {
+        return Err(ErrorCode::DifferentWhirlpoolTickArrayAccount.into());
+    }
+    Ok(tick_array)
+}
+
+fn swap_hop(
+    hop_account: &RouteSwapHopAccounts,
+    hop: &RouteSwapHop,
+    amount: u64,
+    amount_specified_is_input: bool,
+    timestamp: u64,
+) -> Result<PostSwapUpdate> {
+    let mut swap_tick_sequence = SwapTickSequence::new(
+        hop_account.tick_array_0.load_mut()?,
+        hop_account.tick_array_1.load_mut().ok(),
+        hop_account.tick_array_2.load_mut().ok(),
+    );
+    swap(
+        &hop_account.whirlpool,
+        &mut swap_tick_sequence,
+        amount,
+        hop.sqrt_price_limit,
+        amount_specified_is_input,
+        hop.a_to_b,
+        timestamp,
+    )
+}
+
+fn swap_input_amount(swap_update: &PostSwapUpdate, a_to_b: bool) -> u64 {
+    if a_to_b {
+        swap_update.amount_a
+    } else {
+        swap_update.amount_b
+    }
+}
+
+fn swap_output_amount(swap_update: &PostSwapUpdate, a_to_b: bool) -> u64 {
+    if a_to_b {
+        swap_update.amount_b
+    } else {
+        swap_update.amount_a
+    }
+}
+
+// Load the accounts of every hop and check that they form a valid route
+fn load_route_swap_hop_accounts<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    hops: &[RouteSwapHop],
+) -> Result<Vec<RouteSwapHopAccounts<'info>>> {
+    if hops.is_empty() || remaining_accounts.len() != hops.len() * ROUTE_SWAP_HOP_ACCOUNTS_LEN {
+        return Err(ErrorCode::InvalidRouteSwapHops.into());
+    }
+
+    let hop_accounts = remaining_accounts
+        .chunks(ROUTE_SWAP_HOP_ACCOUNTS_LEN)
+        .zip(hops.iter())
+        .map(|(accounts, hop)| RouteSwapHopAccounts::try_from(accounts, hop.oracle_bump))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Don't allow the route to pass through the same whirlpool twice
+    for (i, hop_account) in hop_accounts.iter().enumerate() {
+        let whirlpool = hop_account.whirlpool.key();
+        if hop_accounts[..i]
+            .iter()
+            .any(|prev| prev.whirlpool.key() == whirlpool)
+        {
+            return Err(ErrorCode::DuplicateRouteSwapPool.into());
+        }
+    }
+
+    // The output mint of each hop must be the input mint of the next one
+    for (accounts, route) in hop_accounts.windows(2).zip(hops.windows(2)) {
+        let output_mint = if route[0].a_to_b {
+            accounts[0].whirlpool.token_mint_b
+        } else {
+            accounts[0].whirlpool.token_mint_a
+        };
+        let input_mint = if route[1].a_to_b {
+            accounts[1].whirlpool.token_mint_a
+        } else {
+            accounts[1].whirlpool.token_mint_b
+        };
+        if output_mint != input_mint {
+            return Err(ErrorCode::InvalidIntermediaryMint.into());
+        }
+    }
+
+    Ok(hop_accounts)
+}
+
+// Calculate the swap of every hop, in route order, and check the amounts against the threshold
+fn calculate_route_swap(
+    hop_accounts: &[RouteSwapHopAccounts],
+    hops: &[RouteSwapHop],
+    amount: u64,
+    other_amount_threshold: u64,
+    amount_specified_is_input: bool,
+    timestamp: u64,
+) -> Result<Vec<PostSwapUpdate>> {
+    let swap_updates = if amount_specified_is_input {
+        // Exact-in: the calculations occur from the first hop to the last,
+        // each hop taking the output of the previous hop as its input.
+        let mut hop_amount = amount;
+        let mut swap_updates = Vec::with_capacity(hops.len());
+        for (hop_account, hop) in hop_accounts.iter().zip(hops.iter()) {
+            let swap_update = swap_hop(
+                hop_account,
+                hop,
+                hop_amount,
+                amount_specified_is_input,
+                timestamp,
+            )?;
+            hop_amount = swap_output_amount(&swap_update, hop.a_to_b);
+            swap_updates.push(swap_update);
+        }
+        swap_updates
+    } else {
+        // Exact-out: the calculations occur from the last hop to the first,
+        // each hop producing the input required by the next hop as its output.
+        // The actual swaps still occur from the first hop to the last.
+        let mut hop_amount = amount;
+        let mut swap_updates = Vec::with_capacity(hops.len());
+        for (hop_account, hop) in hop_accounts.iter().zip(hops.iter()).rev() {
+            let swap_update = swap_hop(
+                hop_account,
+                hop,
+                hop_amount,
+                amount_specified_is_input,
+                timestamp,
+            )?;
+            hop_amount = swap_input_amount(&swap_update, hop.a_to_b);
+            swap_updates.push(swap_update);
+        }
+        swap_updates.reverse();
+        swap_updates
+    };
+
+    // All output token of each hop should be consumed by the next hop.
+    // A partial fill on any hop (e.g. a price limit was hit) would leave the intermediate token behind.
+    for (updates, route) in swap_updates.windows(2).zip(hops.windows(2)) {
+        if swap_output_amount(&updates[0], route[0].a_to_b)
+            != swap_input_amount(&updates[1], route[1].a_to_b)
+        {
+            return Err(ErrorCode::IntermediateTokenAmountMismatch.into());
+        }
+    }
+
+    if amount_specified_is_input {
+        // The slippage we care about is the output of the last hop.
+        let last = swap_updates.len() - 1;
+        let output_amount = swap_output_amount(&swap_updates[last], hops[last].a_to_b);
+        if output_amount < other_amount_threshold {
+            return Err(ErrorCode::AmountOutBelowMinimum.into());
+        }
+    } else {
+        // The slippage we care about is the input of the first hop.
+        let input_amount = swap_input_amount(&swap_updates[0], hops[0].a_to_b);
+        if input_amount > other_amount_threshold {
+            return Err(ErrorCode::AmountInAboveMaximum.into());
+        }
+    }
+
+    Ok(swap_updates)
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RouteSwap<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    amount_specified_is_input: bool,
+    hops: Vec<RouteSwapHop>,
+) -> Result<()> {
+    let mut hop_accounts = load_route_swap_hop_accounts(ctx.remaining_accounts, &hops)?;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    // Record the pre-swap state of every pool before any of them is updated
+    for hop_account in hop_accounts.iter() {
+        update_oracle(hop_account.oracle, &hop_account.whirlpool, timestamp)?;
+    }
+
+    let swap_updates = calculate_route_swap(
+        &hop_accounts,
+        &hops,
+        amount,
+        other_amount_threshold,
+        amount_specified_is_input,
+        timestamp,
+    )?;
+
+    for ((hop_account, hop), swap_update) in
+        hop_accounts.iter_mut().zip(hops.iter()).zip(swap_updates)
+    {
+        update_and_swap_whirlpool(
+            &mut hop_account.whirlpool,
+            &ctx.accounts.token_authority,
+            &hop_account.token_owner_account_a,
+            &hop_account.token_owner_account_b,
+            &hop_account.token_vault_a,
+            &hop_account.token_vault_b,
+            &ctx.accounts.token_program,
+            swap_update,
+            hop.a_to_b,
+            timestamp,
+        )?;
+
+        // Hop whirlpools are not part of the accounts struct, so they must be persisted here
+        hop_account.whirlpool.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod route_swap_tests {
+    use super::*;
+    use crate::math::sqrt_price_from_tick_index;
+    use crate::state::{whirlpool_builder::WhirlpoolBuilder, TICK_ARRAY_SIZE};
+    use anchor_lang::Discriminator;
+    use solana_program::program_pack::Pack;
+    use spl_token::state::{Account as SplTokenAccount, AccountState};
+
+    const TICK_SPACING: u16 = 8;
+    const TICK_CURRENT_INDEX: i32 = 352;
+
+    struct TestPool {
+        address: Pubkey,
+        whirlpool: Whirlpool,
+    }
+
+    fn test_pool(token_mint_a: Pubkey, token_mint_b: Pubkey) -> TestPool {
+        let mut whirlpool = WhirlpoolBuilder::new()
+            .liquidity(1_000_000_000_000)
+            .tick_spacing(TICK_SPACING)
+            .tick_current_index(TICK_CURRENT_INDEX)
+            .sqrt_price(sqrt_price_from_tick_index(TICK_CURRENT_INDEX))
+            .build();
+        whirlpool.token_mint_a = token_mint_a;
+        whirlpool.token_vault_a = Pubkey::new_unique();
+        whirlpool.token_mint_b = token_mint_b;
+        whirlpool.token_vault_b = Pubkey::new_unique();
+        TestPool {
+            address: Pubkey::new_unique(),
+            whirlpool,
+        }
+    }
+
+    fn account_info(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            true,
+            Box::leak(Box::new(1_000_000)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )
+    }
+
+    fn token_account_info(key: Pubkey, mint: Pubkey) -> AccountInfo<'static> {
+        let mut data = vec![0; SplTokenAccount::LEN];
+        SplTokenAccount::pack(
+            SplTokenAccount {
+                mint,
+                owner: Pubkey::new_unique(),
+                state: AccountState::Initialized,
+                ..Default::default()
+            },
+            &mut data,
+        )
+        .unwrap();
+        account_info(key, token::ID, data)
+    }
+
+    fn tick_array_info(whirlpool: Pubkey, start_tick_index: i32) -> AccountInfo<'static> {
+        let tick_array = TickArray {
+            start_tick_index,
+            whirlpool,
+            ..Default::default()
+        };
+        let mut data = TickArray::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&tick_array));
+        account_info(Pubkey::new_unique(), crate::ID, data)
+    }
+
+    impl TestPool {
+        fn oracle_address(&self) -> (Pubkey, u8) {
+            Pubkey::find_program_address(&[b"oracle", self.address.as_ref()], &crate::ID)
+        }
+
+        fn hop(&self, a_to_b: bool) -> RouteSwapHop {
+            RouteSwapHop {
+                a_to_b,
+                sqrt_price_limit: sqrt_price_from_tick_index(if a_to_b { 0 } else { 704 }),
+                oracle_bump: self.oracle_address().1,
+            }
+        }
+
+        // The tick arrays cover an a to b swap from the current tick
+        fn accounts(&self) -> Vec<AccountInfo<'static>> {
+            let mut data = Vec::new();
+            self.whirlpool.try_serialize(&mut data).unwrap();
+            data.resize(Whirlpool::LEN, 0);
+
+            let ticks_in_array = TICK_ARRAY_SIZE * TICK_SPACING as i32;
+            vec![
+                account_info(self.address, crate::ID, data),
+                token_account_info(Pubkey::new_unique(), self.whirlpool.token_mint_a),
+                token_account_info(self.whirlpool.token_vault_a, self.whirlpool.token_mint_a),
+                token_account_info(Pubkey::new_unique(), self.whirlpool.token_mint_b),
+                token_account_info(self.whirlpool.token_vault_b, self.whirlpool.token_mint_b),
+                tick_array_info(self.address, 0),
+                tick_array_info(self.address, -ticks_in_array),
+                tick_array_info(self.address, -2 * ticks_in_array),
+                account_info(self.oracle_address().0, Pubkey::default(), vec![]),
+            ]
+        }
+    }
+
+    fn load(
+        accounts: Vec<AccountInfo<'static>>,
+        hops: &[RouteSwapHop],
+    ) -> Result<Vec<RouteSwapHopAccounts<'static>>> {
+        load_route_swap_hop_accounts(Box::leak(accounts.into_boxed_slice()), hops)
+    }
+
+    fn load_err(accounts: Vec<AccountInfo<'static>>, hops: &[RouteSwapHop]) -> Error {
+        match load(accounts, hops) {
+            Ok(_) => panic!("expected the route accounts to be rejected"),
+            Err(err) => err,
+        }
+    }
+
+    // Two pools routing token A to token C through token B
+    fn test_route() -> (Vec<AccountInfo<'static>>, Vec<RouteSwapHop>) {
+        let (mint_a, mint_b, mint_c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let pool_one = test_pool(mint_a, mint_b);
+        let pool_two = test_pool(mint_b, mint_c);
+        let mut accounts = pool_one.accounts();
+        accounts.extend(pool_two.accounts());
+        (accounts, vec![pool_one.hop(true), pool_two.hop(true)])
+    }
+
+    #[test]
+    fn test_load_valid_route() {
+        let (accounts, hops) = test_route();
+        let hop_accounts = load(accounts, &hops).unwrap();
+        assert_eq!(hop_accounts.len(), 2);
+    }
+
+    #[test]
+    fn test_wrong_account_count() {
+        let (accounts, hops) = test_route();
+        assert_eq!(
+            load_err(accounts[..ROUTE_SWAP_HOP_ACCOUNTS_LEN].to_vec(), &hops),
+            ErrorCode::InvalidRouteSwapHops.into()
+        );
+
+        let (accounts, hops) = test_route();
+        assert_eq!(
+            load_err(accounts, &hops[..1]),
+            ErrorCode::InvalidRouteSwapHops.into()
+        );
+        assert_eq!(
+            load_err(vec![], &[]),
+            ErrorCode::InvalidRouteSwapHops.into()
+        );
+    }
+
+    #[test]
+    fn test_intermediary_mint_mismatch() {
+        let (accounts, mut hops) = test_route();
+        // The first hop now outputs token A, which the second pool does not trade
+        hops[0].a_to_b = false;
+        assert_eq!(
+            load_err(accounts, &hops),
+            ErrorCode::InvalidIntermediaryMint.into()
+        );
+    }
+
+    #[test]
+    fn test_duplicate_pool() {
+        let pool = test_pool(Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = pool.accounts();
+        accounts.extend(pool.accounts());
+        assert_eq!(
+            load_err(accounts, &[pool.hop(true), pool.hop(false)]),
+            ErrorCode::DuplicateRouteSwapPool.into()
+        );
+    }
+
+    #[test]
+    fn test_vault_mismatch() {
+        let pool = test_pool(Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = pool.accounts();
+        accounts[4] = token_account_info(Pubkey::new_unique(), pool.whirlpool.token_mint_b);
+        assert_eq!(
+            load_err(accounts, &[pool.hop(true)]),
+            AnchorErrorCode::ConstraintAddress.into()
+        );
+    }
+
+    #[test]
+    fn test_owner_account_mint_mismatch() {
+        let pool = test_pool(Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = pool.accounts();
+        accounts[1] = token_account_info(Pubkey::new_unique(), pool.whirlpool.token_mint_b);
+        assert_eq!(
+            load_err(accounts, &[pool.hop(true)]),
+            AnchorErrorCode::ConstraintRaw.into()
+        );
+    }
+
+    #[test]
+    fn test_tick_array_not_writable() {
+        let pool = test_pool(Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = pool.accounts();
+        accounts[7].is_writable = false;
+        assert_eq!(
+            load_err(accounts, &[pool.hop(true)]),
+            AnchorErrorCode::ConstraintMut.into()
+        );
+    }
+
+    #[test]
+    fn test_tick_array_of_other_whirlpool() {
+        let pool = test_pool(Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = pool.accounts();
+        accounts[6] = tick_array_info(Pubkey::new_unique(), 0);
+        assert_eq!(
+            load_err(accounts, &[pool.hop(true)]),
+            ErrorCode::DifferentWhirlpoolTickArrayAccount.into()
+        );
+    }
+
+    #[test]
+    fn test_oracle_mismatch() {
+        let pool = test_pool(Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = pool.accounts();
+        accounts[8] = account_info(Pubkey::new_unique(), Pubkey::default(), vec![]);
+        assert_eq!(
+            load_err(accounts, &[pool.hop(true)]),
+            AnchorErrorCode::ConstraintSeeds.into()
+        );
+
+        let mut hop = pool.hop(true);
+        hop.oracle_bump = hop.oracle_bump.wrapping_sub(1);
+        assert_eq!(
+            load_err(pool.accounts(), &[hop]),
+            AnchorErrorCode::ConstraintSeeds.into()
+        );
+    }
+
+    #[test]
+    fn test_exact_in_threshold() {
+        let (accounts, hops) = test_route();
+        let hop_accounts = load(accounts, &hops).unwrap();
+
+        let swap_updates =
+            calculate_route_swap(&hop_accounts, &hops, 1_000_000, 0, true, 1).unwrap();
+        assert_eq!(swap_updates[0].amount_a, 1_000_000);
+        assert_eq!(swap_updates[0].amount_b, swap_updates[1].amount_a);
+        let output_amount = swap_updates[1].amount_b;
+        assert!(output_amount > 0);
+
+        assert!(
+            calculate_route_swap(&hop_accounts, &hops, 1_000_000, output_amount, true, 1).is_ok()
+        );
+        assert_eq!(
+            calculate_route_swap(&hop_accounts, &hops, 1_000_000, output_amount + 1, true, 1)
+                .unwrap_err(),
+            ErrorCode::AmountOutBelowMinimum.into()
+        );
+    }
+
+    #[test]
+    fn test_exact_out_threshold() {
+        let (accounts, hops) = test_route();
+        let hop_accounts = load(accounts, &hops).unwrap();
+
+        let swap_updates =
+            calculate_route_swap(&hop_accounts, &hops, 1_000_000, u64::MAX, false, 1).unwrap();
+        assert_eq!(swap_updates[1].amount_b, 1_000_000);
+        assert_eq!(swap_updates[0].amount_b, swap_updates[1].amount_a);
+        let input_amount = swap_updates[0].amount_a;
+
+        assert!(
+            calculate_route_swap(&hop_accounts, &hops, 1_000_000, input_amount, false, 1).is_ok()
+        );
+        assert_eq!(
+            calculate_route_swap(&hop_accounts, &hops, 1_000_000, input_amount - 1, false, 1)
+                .unwrap_err(),
+            ErrorCode::AmountInAboveMaximum.into()
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct SetRewardAuthority<'info> {
+    #[account(mut)]
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(
+        constraint = (reward_index as usize) < NUM_REWARDS @ ErrorCode::InvalidRewardIndex,
+        address = whirlpool.reward_infos[reward_index as usize].authority
+    )]
+    pub reward_authority: Signer<'info>,
+
+    /// CHECK: safe, the account that will be new authority can be arbitrary
+    pub new_reward_authority: UncheckedAccount<'info>,
+}
+
+/// Set the reward authority at the given index. Only the current reward authority has
+/// permission to invoke this instruction.
+pub fn handler(ctx: Context<SetRewardAuthority>, reward_index: u8) -> Result<()> {
+    ctx.accounts.whirlpool.update_reward_authority(
+        reward_index as usize,
+        ctx.accounts.new_reward_authority.key(),
+    )
+}
@@ -1,7 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
 
-use crate::state::{TickArray, Whirlpool};
+use crate::{
+    errors::ErrorCode,
+    manager::swap_manager::*,
+    state::{TickArray, Whirlpool},
+    util::{
+        load_remaining_tick_arrays, to_timestamp_u64, update_and_swap_whirlpool, update_oracle,
+        SwapTickSequence,
+    },
+};
 
 #[derive(Accounts)]
 pub struct Swap<'info> {
@@ -33,6 +41,66 @@ pub struct Swap<'info> {
     pub tick_array_2: AccountLoader<'info, TickArray>,
 
     #[account(seeds = [b"oracle", whirlpool.key().as_ref()],bump)]
-    /// CHECK: Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: Observations are only recorded if the oracle is initialized and passed as writable
     pub oracle: UncheckedAccount<'info>,
 }
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Swap<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool,
+) -> Result<()> {
+    let whirlpool = &mut ctx.accounts.whirlpool;
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    update_oracle(&ctx.accounts.oracle, whirlpool, timestamp)?;
+
+    // Additional tick arrays can be passed through remaining accounts to extend the sequence
+    let remaining_tick_arrays =
+        load_remaining_tick_arrays(ctx.remaining_accounts, &[whirlpool.key()])?.remove(0);
+
+    let mut swap_tick_sequence = SwapTickSequence::new(
+        ctx.accounts.tick_array_0.load_mut()?,
+        ctx.accounts.tick_array_1.load_mut().ok(),
+        ctx.accounts.tick_array_2.load_mut().ok(),
+    );
+    swap_tick_sequence.extend(&remaining_tick_arrays)?;
+
+    let swap_update = swap(
+        whirlpool,
+        &mut swap_tick_sequence,
+        amount,
+        sqrt_price_limit,
+        amount_specified_is_input,
+        a_to_b,
+        timestamp,
+    )?;
+
+    if amount_specified_is_input {
+        if (a_to_b && other_amount_threshold > swap_update.amount_b)
+            || (!a_to_b && other_amount_threshold > swap_update.amount_a)
+        {
+            return Err(ErrorCode::AmountOutBelowMinimum.into());
+        }
+    } else if (a_to_b && other_amount_threshold < swap_update.amount_a)
+        || (!a_to_b && other_amount_threshold < swap_update.amount_b)
+    {
+        return Err(ErrorCode::AmountInAboveMaximum.into());
+    }
+
+    update_and_swap_whirlpool(
+        whirlpool,
+        &ctx.accounts.token_authority,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_program,
+        swap_update,
+        a_to_b,
+        timestamp,
+    )
+}
@@ -1,9 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
 
-use crate::state::{TickArray, Whirlpool};
-
-use super::swap;
+use crate::{
+    errors::ErrorCode,
+    manager::swap_manager::*,
+    state::{TickArray, Whirlpool},
+    util::{
+        load_remaining_tick_arrays, to_timestamp_u64, update_and_swap_whirlpool, update_oracle,
+        SwapTickSequence,
+    },
+};
 
 #[derive(Accounts)]
 pub struct TwoHopSwap<'info> {
@@ -57,10 +63,206 @@ pub struct TwoHopSwap<'info> {
     pub tick_array_two_2: AccountLoader<'info, TickArray>,
 
     #[account(seeds = [b"oracle", whirlpool_one.key().as_ref()],bump)]
-    /// CHECK: Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: Observations are only recorded if the oracle is initialized and passed as writable
     pub oracle_one: UncheckedAccount<'info>,
 
     #[account(seeds = [b"oracle", whirlpool_two.key().as_ref()],bump)]
-    /// CHECK: Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: Observations are only recorded if the oracle is initialized and passed as writable
     pub oracle_two: UncheckedAccount<'info>,
 }
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TwoHopSwap<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    amount_specified_is_input: bool,
+    a_to_b_one: bool,
+    a_to_b_two: bool,
+    sqrt_price_limit_one: u128,
+    sqrt_price_limit_two: u128,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    let whirlpool_one = &mut ctx.accounts.whirlpool_one;
+    let whirlpool_two = &mut ctx.accounts.whirlpool_two;
+
+    // Don't allow swaps on the same whirlpool
+    if whirlpool_one.key() == whirlpool_two.key() {
+        return Err(ErrorCode::DuplicateTwoHopPool.into());
+    }
+
+    let swap_one_output_mint = if a_to_b_one {
+        whirlpool_one.token_mint_b
+    } else {
+        whirlpool_one.token_mint_a
+    };
+
+    let swap_two_input_mint = if a_to_b_two {
+        whirlpool_two.token_mint_a
+    } else {
+        whirlpool_two.token_mint_b
+    };
+    if swap_one_output_mint != swap_two_input_mint {
+        return Err(ErrorCode::InvalidIntermediaryMint.into());
+    }
+
+    // Record the pre-swap state of both pools before either of them is updated
+    update_oracle(&ctx.accounts.oracle_one, whirlpool_one, timestamp)?;
+    update_oracle(&ctx.accounts.oracle_two, whirlpool_two, timestamp)?;
+
+    // Additional tick arrays for either pool can be passed through remaining accounts.
+    // Each one is appended to the sequence of the whirlpool it belongs to.
+    let mut remaining_tick_arrays = load_remaining_tick_arrays(
+        ctx.remaining_accounts,
+        &[whirlpool_one.key(), whirlpool_two.key()],
+    )?;
+    let remaining_tick_arrays_two = remaining_tick_arrays.remove(1);
+    let remaining_tick_arrays_one = remaining_tick_arrays.remove(0);
+
+    let mut swap_tick_sequence_one = SwapTickSequence::new(
+        ctx.accounts.tick_array_one_0.load_mut()?,
+        ctx.accounts.tick_array_one_1.load_mut().ok(),
+        ctx.accounts.tick_array_one_2.load_mut().ok(),
+    );
+    swap_tick_sequence_one.extend(&remaining_tick_arrays_one)?;
+
+    let mut swap_tick_sequence_two = SwapTickSequence::new(
+        ctx.accounts.tick_array_two_0.load_mut()?,
+        ctx.accounts.tick_array_two_1.load_mut().ok(),
+        ctx.accounts.tick_array_two_2.load_mut().ok(),
+    );
+    swap_tick_sequence_two.extend(&remaining_tick_arrays_two)?;
+
+    let (swap_update_one, swap_update_two) = if amount_specified_is_input {
+        // If the amount specified is input, this means we are doing exact-in
+        // and the swap calculations occur from Swap 1 => Swap 2
+        // and the swaps occur from Swap 1 => Swap 2
+        let swap_calc_one = swap(
+            whirlpool_one,
+            &mut swap_tick_sequence_one,
+            amount,
+            sqrt_price_limit_one,
+            amount_specified_is_input, // true
+            a_to_b_one,
+            timestamp,
+        )?;
+
+        // Swap two input is the output of swap one
+        let swap_two_input_amount = if a_to_b_one {
+            swap_calc_one.amount_b
+        } else {
+            swap_calc_one.amount_a
+        };
+
+        let swap_calc_two = swap(
+            whirlpool_two,
+            &mut swap_tick_sequence_two,
+            swap_two_input_amount,
+            sqrt_price_limit_two,
+            amount_specified_is_input, // true
+            a_to_b_two,
+            timestamp,
+        )?;
+        (swap_calc_one, swap_calc_two)
+    } else {
+        // If the amount specified is output, this means we need to invert the ordering of the calculations
+        // and the swap calculations occur from Swap 2 => Swap 1
+        // but the actual swaps occur from Swap 1 => Swap 2 (to ensure that the intermediate token exists in the account)
+        let swap_calc_two = swap(
+            whirlpool_two,
+            &mut swap_tick_sequence_two,
+            amount,
+            sqrt_price_limit_two,
+            amount_specified_is_input, // false
+            a_to_b_two,
+            timestamp,
+        )?;
+
+        // The output of swap 1 is input of swap_calc_two
+        let swap_one_output_amount = if a_to_b_two {
+            swap_calc_two.amount_a
+        } else {
+            swap_calc_two.amount_b
+        };
+
+        let swap_calc_one = swap(
+            whirlpool_one,
+            &mut swap_tick_sequence_one,
+            swap_one_output_amount,
+            sqrt_price_limit_one,
+            amount_specified_is_input, // false
+            a_to_b_one,
+            timestamp,
+        )?;
+        (swap_calc_one, swap_calc_two)
+    };
+
+    // All output token of swap one should be consumed by swap two.
+    // A partial fill on either side (e.g. a price limit was hit) would leave the intermediate token behind.
+    let swap_calc_one_output = if a_to_b_one {
+        swap_update_one.amount_b
+    } else {
+        swap_update_one.amount_a
+    };
+    let swap_calc_two_input = if a_to_b_two {
+        swap_update_two.amount_a
+    } else {
+        swap_update_two.amount_b
+    };
+    if swap_calc_one_output != swap_calc_two_input {
+        return Err(ErrorCode::IntermediateTokenAmountMismatch.into());
+    }
+
+    if amount_specified_is_input {
+        // If amount_specified_is_input == true, then we have a variable amount of output
+        // The slippage we care about is the output of the second swap.
+        let output_amount = if a_to_b_two {
+            swap_update_two.amount_b
+        } else {
+            swap_update_two.amount_a
+        };
+
+        // If we have received less than the minimum out, throw an error
+        if output_amount < other_amount_threshold {
+            return Err(ErrorCode::AmountOutBelowMinimum.into());
+        }
+    } else {
+        // If amount_specified_is_input == false, then we have a variable amount of input
+        // The slippage we care about is the input of the first swap
+        let input_amount = if a_to_b_one {
+            swap_update_one.amount_a
+        } else {
+            swap_update_one.amount_b
+        };
+        if input_amount > other_amount_threshold {
+            return Err(ErrorCode::AmountInAboveMaximum.into());
+        }
+    }
+
+    update_and_swap_whirlpool(
+        whirlpool_one,
+        &ctx.accounts.token_authority,
+        &ctx.accounts.token_owner_account_one_a,
+        &ctx.accounts.token_owner_account_one_b,
+        &ctx.accounts.token_vault_one_a,
+        &ctx.accounts.token_vault_one_b,
+        &ctx.accounts.token_program,
+        swap_update_one,
+        a_to_b_one,
+        timestamp,
+    )?;
+
+    update_and_swap_whirlpool(
+        whirlpool_two,
+        &ctx.accounts.token_authority,
+        &ctx.accounts.token_owner_account_two_a,
+        &ctx.accounts.token_owner_account_two_b,
+        &ctx.accounts.token_vault_two_a,
+        &ctx.accounts.token_vault_two_b,
+        &ctx.accounts.token_program,
+        swap_update_two,
+        a_to_b_two,
+        timestamp,
+    )
+}
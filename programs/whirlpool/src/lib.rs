@@ -17,17 +17,297 @@ pub mod state;
 #[doc(hidden)]
 pub mod util;
 
+use crate::state::{OpenPositionBumps, OpenPositionWithMetadataBumps, WhirlpoolBumps};
 use instructions::*;
 
 #[program]
 pub mod whirlpool {
     use super::*;
 
+    /// Initializes a WhirlpoolsConfig account that hosts info & authorities
+    /// required to govern a set of Whirlpools.
+    ///
+    /// ### Parameters
+    /// - `fee_authority` - Authority authorized to initialize fee-tiers and set customs fee fees.
+    /// - `collect_protocol_fees_authority` - Authority authorized to collect protocol fees.
+    /// - `reward_emissions_super_authority` - Authority authorized to set reward authorities in pools.
+    /// - `default_protocol_fee_rate` - The default protocol fee rate. Stored as a basis point of the total fees collected by feeRate.
+    ///
+    /// #### Special Errors
+    /// - `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE.
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        fee_authority: Pubkey,
+        collect_protocol_fees_authority: Pubkey,
+        reward_emissions_super_authority: Pubkey,
+        default_protocol_fee_rate: u16,
+    ) -> Result<()> {
+        instructions::initialize_config::handler(
+            ctx,
+            fee_authority,
+            collect_protocol_fees_authority,
+            reward_emissions_super_authority,
+            default_protocol_fee_rate,
+        )
+    }
+
+    /// Initializes a Whirlpool account.
+    /// Fee rate is set to the default values on the config and supplied fee_tier.
+    ///
+    /// ### Parameters
+    /// - `bumps` - The bump value when deriving the PDA of the Whirlpool address.
+    /// - `tick_spacing` - The desired tick spacing for this pool.
+    /// - `initial_sqrt_price` - The desired initial sqrt-price for this pool
+    ///
+    /// #### Special Errors
+    /// - `InvalidTokenMintOrder` - The order of mints have to be ordered by key, token_mint_a < token_mint_b.
+    /// - `SqrtPriceOutOfBounds` - Provided initial_sqrt_price is not between 2^-64 to 2^64.
+    /// - `FeeRateMaxExceeded` - The fee tier's default_fee_rate exceeds MAX_FEE_RATE.
+    pub fn initialize_pool(
+        ctx: Context<InitializePool>,
+        bumps: WhirlpoolBumps,
+        tick_spacing: u16,
+        initial_sqrt_price: u128,
+    ) -> Result<()> {
+        instructions::initialize_pool::handler(ctx, bumps, tick_spacing, initial_sqrt_price)
+    }
+
+    /// Initializes a tick_array account to represent a tick-range in a Whirlpool.
+    /// The tick array is a PDA derived from the whirlpool and its `start_tick_index`,
+    /// so it can only be created once. Anyone can initialize a tick array.
+    ///
+    /// ### Parameters
+    /// - `start_tick_index` - The starting tick index for this tick-array.
+    ///                        Has to be a multiple of TickArray size & the tick spacing of this pool.
+    ///
+    /// #### Special Errors
+    /// - `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of
+    ///                        TICK_ARRAY_SIZE * tick spacing.
+    pub fn initialize_tick_array(
+        ctx: Context<InitializeTickArray>,
+        start_tick_index: i32,
+    ) -> Result<()> {
+        instructions::initialize_tick_array::handler(ctx, start_tick_index)
+    }
+
+    /// Initializes a fee_tier account usable by Whirlpools in a WhirlpoolConfig space.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `tick_spacing` - The tick-spacing that this fee-tier suggests the default_fee_rate for.
+    /// - `default_fee_rate` - The default fee rate that a pool will use if the pool uses this
+    ///                        fee tier during initialization.
+    ///
+    /// #### Special Errors
+    /// - `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.
+    pub fn initialize_fee_tier(
+        ctx: Context<InitializeFeeTier>,
+        tick_spacing: u16,
+        default_fee_rate: u16,
+    ) -> Result<()> {
+        instructions::initialize_fee_tier::handler(ctx, tick_spacing, default_fee_rate)
+    }
+
+    /// Initializes the oracle account of a Whirlpool. Once initialized, swaps and liquidity
+    /// changes that pass the oracle as a writable account record an observation of the pool's
+    /// tick and liquidity, at most once per second.
+    /// The oracle starts with a single observation slot.
+    pub fn initialize_oracle(ctx: Context<InitializeOracle>) -> Result<()> {
+        instructions::initialize_oracle::handler(ctx)
+    }
+
+    /// Increases the number of observations stored by a Whirlpool's oracle.
+    /// The new slots are used once the oracle's ring buffer wraps around to them.
+    ///
+    /// ### Parameters
+    /// - `observation_cardinality_next` - The number of observations the oracle will store.
+    ///
+    /// #### Special Errors
+    /// - `InvalidOracleCardinality` - If the provided cardinality does not exceed the current one
+    ///                                or exceeds MAX_OBSERVATIONS.
+    pub fn increase_oracle_cardinality(
+        ctx: Context<IncreaseOracleCardinality>,
+        observation_cardinality_next: u16,
+    ) -> Result<()> {
+        instructions::increase_oracle_cardinality::handler(ctx, observation_cardinality_next)
+    }
+
+    /// Initialize reward for a Whirlpool. A pool can only support up to a set number of rewards.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
+    ///                        reward-index in this Whirlpool
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized
+    ///                          index in this pool, or exceeds NUM_REWARDS.
+    pub fn initialize_reward(ctx: Context<InitializeReward>, reward_index: u8) -> Result<()> {
+        instructions::initialize_reward::handler(ctx, reward_index)
+    }
+
+    /// Set the reward emissions for a reward in a Whirlpool.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
+    ///                        reward-index in this Whirlpool
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.
+    /// - `emissions_per_second_x64` - The amount of rewards emitted in this pool.
+    ///
+    /// #### Special Errors
+    /// - `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit
+    ///                                     more than a day of desired emissions.
+    /// - `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.
+    pub fn set_reward_emissions(
+        ctx: Context<SetRewardEmissions>,
+        reward_index: u8,
+        emissions_per_second_x64: u128,
+    ) -> Result<()> {
+        instructions::set_reward_emissions::handler(ctx, reward_index, emissions_per_second_x64)
+    }
+
+    /// Open a position in a Whirlpool. A unique token will be minted to represent the position
+    /// in the users wallet. The position will start off with 0 liquidity.
+    ///
+    /// ### Parameters
+    /// - `tick_lower_index` - The tick specifying the lower end of the position range.
+    /// - `tick_upper_index` - The tick specifying the upper end of the position range.
+    ///
+    /// #### Special Errors
+    /// - `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of
+    ///                        the tick-spacing in this pool.
+    pub fn open_position(
+        ctx: Context<OpenPosition>,
+        bumps: OpenPositionBumps,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+    ) -> Result<()> {
+        instructions::open_position::handler(ctx, bumps, tick_lower_index, tick_upper_index)
+    }
+
+    /// Open a position in a Whirlpool. A unique token will be minted to represent the position
+    /// in the users wallet. Additional Metaplex metadata is appended to identify the token.
+    /// The position will start off with 0 liquidity.
+    ///
+    /// ### Parameters
+    /// - `tick_lower_index` - The tick specifying the lower end of the position range.
+    /// - `tick_upper_index` - The tick specifying the upper end of the position range.
+    ///
+    /// #### Special Errors
+    /// - `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of
+    ///                        the tick-spacing in this pool.
+    pub fn open_position_with_metadata(
+        ctx: Context<OpenPositionWithMetadata>,
+        bumps: OpenPositionWithMetadataBumps,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+    ) -> Result<()> {
+        instructions::open_position_with_metadata::handler(
+            ctx,
+            bumps,
+            tick_lower_index,
+            tick_upper_index,
+        )
+    }
+
+    /// Add liquidity to a position in the Whirlpool. This call also updates the position's accrued fees and rewards.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Remaining Accounts
+    /// - `oracle` - Optional. The oracle of the Whirlpool, passed as writable to record an observation.
+    ///
+    /// ### Parameters
+    /// - `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.
+    /// - `token_max_a` - The maximum amount of tokenA the user is willing to deposit.
+    /// - `token_max_b` - The maximum amount of tokenB the user is willing to deposit.
+    ///
+    /// #### Special Errors
+    /// - `LiquidityZero` - Provided liquidity amount is zero.
+    /// - `LiquidityTooHigh` - Provided liquidity exceeds i128::MAX.
+    /// - `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.
+    pub fn increase_liquidity(
+        ctx: Context<ModifyLiquidity>,
+        liquidity_amount: u128,
+        token_max_a: u64,
+        token_max_b: u64,
+    ) -> Result<()> {
+        instructions::increase_liquidity::handler(ctx, liquidity_amount, token_max_a, token_max_b)
+    }
+
+    /// Withdraw liquidity from a position in the Whirlpool. This call also updates the position's accrued fees and rewards.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Remaining Accounts
+    /// - `oracle` - Optional. The oracle of the Whirlpool, passed as writable to record an observation.
+    ///
+    /// ### Parameters
+    /// - `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.
+    /// - `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.
+    /// - `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.
+    ///
+    /// #### Special Errors
+    /// - `LiquidityZero` - Provided liquidity amount is zero.
+    /// - `LiquidityTooHigh` - Provided liquidity exceeds i128::MAX.
+    /// - `TokenMinSubceeded` - The withdrawn amount of token is less than the user defined minimum.
+    pub fn decrease_liquidity(
+        ctx: Context<ModifyLiquidity>,
+        liquidity_amount: u128,
+        token_min_a: u64,
+        token_min_b: u64,
+    ) -> Result<()> {
+        instructions::decrease_liquidity::handler(ctx, liquidity_amount, token_min_a, token_min_b)
+    }
+
+    /// Update the accrued fees and rewards for a position.
+    ///
+    /// #### Special Errors
+    /// - `TickNotFound` - Provided tick array account does not contain the tick for this position.
+    /// - `LiquidityZero` - Position has zero liquidity and therefore already has the most updated fees and reward values.
+    pub fn update_fees_and_rewards(ctx: Context<UpdateFeesAndRewards>) -> Result<()> {
+        instructions::update_fees_and_rewards::handler(ctx)
+    }
+
+    /// Collect fees accrued for this position.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    pub fn collect_fees(ctx: Context<CollectFees>) -> Result<()> {
+        instructions::collect_fees::handler(ctx)
+    }
+
+    /// Collect rewards accrued for this position.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index of the rewards to collect.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index doesn't match any reward index on the Whirlpool.
+    pub fn collect_reward(ctx: Context<CollectReward>, reward_index: u8) -> Result<()> {
+        instructions::collect_reward::handler(ctx, reward_index)
+    }
+
     /// Perform a swap in this Whirlpool
     ///
     /// ### Authority
     /// - "token_authority" - The authority to withdraw tokens from the input token account.
     ///
+    /// ### Remaining Accounts
+    /// - Additional tick-arrays of this Whirlpool, traversed in order after `tick_array_2`.
+    ///
     /// ### Parameters
     /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
     /// - `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).
@@ -44,15 +324,250 @@ pub mod whirlpool {
     /// - `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.
     /// - `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.
     /// - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
-    pub fn swap(
-        ctx: Context<Swap>,
+    /// - `DifferentWhirlpoolTickArrayAccount` - A tick-array in remaining accounts does not belong to this Whirlpool.
+    pub fn swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Swap<'info>>,
         amount: u64,
         other_amount_threshold: u64,
         sqrt_price_limit: u128,
         amount_specified_is_input: bool,
         a_to_b: bool,
     ) -> Result<()> {
-        Ok(())
+        instructions::swap::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+        )
+    }
+
+    /// Close a position in a Whirlpool. Burns the position token in the owner's wallet.
+    ///
+    /// ### Authority
+    /// - "position_authority" - The authority that owns the position token.
+    ///
+    /// #### Special Errors
+    /// - `ClosePositionNotEmpty` - The provided position account is not empty.
+    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+        instructions::close_position::handler(ctx)
+    }
+
+    /// Set the default_fee_rate for a FeeTier
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `default_fee_rate` - The default fee rate that a pool will use if the pool uses this
+    ///                        fee tier during initialization.
+    ///
+    /// #### Special Errors
+    /// - `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.
+    pub fn set_default_fee_rate(
+        ctx: Context<SetDefaultFeeRate>,
+        default_fee_rate: u16,
+    ) -> Result<()> {
+        instructions::set_default_fee_rate::handler(ctx, default_fee_rate)
+    }
+
+    /// Sets the default protocol fee rate for a WhirlpoolConfig
+    /// Protocol fee rate is represented as a basis point.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify protocol fees in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `default_protocol_fee_rate` - The rate charged out of the total fees collected by feeRate for the protocol.
+    ///
+    /// #### Special Errors
+    /// - `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE.
+    pub fn set_default_protocol_fee_rate(
+        ctx: Context<SetDefaultProtocolFeeRate>,
+        default_protocol_fee_rate: u16,
+    ) -> Result<()> {
+        instructions::set_default_protocol_fee_rate::handler(ctx, default_protocol_fee_rate)
+    }
+
+    /// Sets the fee authority for a WhirlpoolConfig.
+    /// The fee authority can set the fee & protocol fee rate for individual pools or
+    /// set the default fee rate for newly minted pools.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the WhirlpoolConfig
+    pub fn set_fee_authority(ctx: Context<SetFeeAuthority>) -> Result<()> {
+        instructions::set_fee_authority::handler(ctx)
+    }
+
+    /// Sets the fee authority to collect protocol fees for a WhirlpoolConfig.
+    /// Only the current collect protocol fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "collect_protocol_fees_authority" - Set authority that can collect protocol fees in the WhirlpoolConfig
+    pub fn set_collect_protocol_fees_authority(
+        ctx: Context<SetCollectProtocolFeesAuthority>,
+    ) -> Result<()> {
+        instructions::set_collect_protocol_fees_authority::handler(ctx)
+    }
+
+    /// Set the whirlpool reward super authority for a WhirlpoolConfig
+    /// Only the current reward super authority has permission to invoke this instruction.
+    /// This instruction will not change the authority on any `WhirlpoolRewardInfo` whirlpool rewards.
+    ///
+    /// ### Authority
+    /// - "reward_emissions_super_authority" - Set authority that can control reward emission for all pools in the WhirlpoolConfig
+    pub fn set_reward_emissions_super_authority(
+        ctx: Context<SetRewardEmissionsSuperAuthority>,
+    ) -> Result<()> {
+        instructions::set_reward_emissions_super_authority::handler(ctx)
+    }
+
+    /// Set the whirlpool reward authority at the provided `reward_index`.
+    /// Only the current reward authority for this reward index has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - Set authority that can control reward emission for this particular reward.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.
+    pub fn set_reward_authority(ctx: Context<SetRewardAuthority>, reward_index: u8) -> Result<()> {
+        instructions::set_reward_authority::handler(ctx, reward_index)
+    }
+
+    /// Set the whirlpool reward authority at the provided `reward_index`.
+    /// Only the current reward super authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "reward_emissions_super_authority" - Set authority that can control reward emission for all pools in the WhirlpoolConfig
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.
+    pub fn set_reward_authority_by_super_authority(
+        ctx: Context<SetRewardAuthorityBySuperAuthority>,
+        reward_index: u8,
+    ) -> Result<()> {
+        instructions::set_reward_authority_by_super_authority::handler(ctx, reward_index)
+    }
+
+    /// Collect the protocol fees accrued in this Whirlpool
+    ///
+    /// ### Authority
+    /// - `collect_protocol_fees_authority` - assigned authority in the WhirlpoolConfig that can collect protocol fees
+    pub fn collect_protocol_fees(ctx: Context<CollectProtocolFees>) -> Result<()> {
+        instructions::collect_protocol_fees::handler(ctx)
+    }
+
+    /// Sets the fee rate for a Whirlpool.
+    /// Fee rate is represented as hundredths of a basis point.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `fee_rate` - The rate that the pool will use to calculate fees going onwards.
+    ///
+    /// #### Special Errors
+    /// - `FeeRateMaxExceeded` - If the provided fee_rate exceeds MAX_FEE_RATE.
+    pub fn set_fee_rate(ctx: Context<SetFeeRate>, fee_rate: u16) -> Result<()> {
+        instructions::set_fee_rate::handler(ctx, fee_rate)
+    }
+
+    /// Sets the protocol fee rate for a Whirlpool.
+    /// Protocol fee rate is represented as a basis point.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `protocol_fee_rate` - The rate that the pool will use to calculate protocol fees going onwards.
+    ///
+    /// #### Special Errors
+    /// - `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE.
+    pub fn set_protocol_fee_rate(
+        ctx: Context<SetProtocolFeeRate>,
+        protocol_fee_rate: u16,
+    ) -> Result<()> {
+        instructions::set_protocol_fee_rate::handler(ctx, protocol_fee_rate)
+    }
+
+    /// Initializes a PositionBundle account that bundles several positions.
+    /// A unique token will be minted to represent the position bundle in the users wallet.
+    pub fn initialize_position_bundle(ctx: Context<InitializePositionBundle>) -> Result<()> {
+        instructions::initialize_position_bundle::handler(ctx)
+    }
+
+    /// Initializes a PositionBundle account that bundles several positions.
+    /// A unique token will be minted to represent the position bundle in the users wallet.
+    /// Additional Metaplex metadata is appended to identify the token.
+    pub fn initialize_position_bundle_with_metadata(
+        ctx: Context<InitializePositionBundleWithMetadata>,
+    ) -> Result<()> {
+        instructions::initialize_position_bundle_with_metadata::handler(ctx)
+    }
+
+    /// Delete a PositionBundle account. Burns the position bundle token in the owner's wallet.
+    ///
+    /// ### Authority
+    /// - `position_bundle_owner` - The owner that owns the position bundle token.
+    ///
+    /// ### Special Errors
+    /// - `PositionBundleNotDeletable` - The provided position bundle has open positions.
+    pub fn delete_position_bundle(ctx: Context<DeletePositionBundle>) -> Result<()> {
+        instructions::delete_position_bundle::handler(ctx)
+    }
+
+    /// Open a bundled position in a Whirlpool. No new tokens are issued
+    /// because the owner of the position bundle becomes the owner of the position.
+    /// The position will start off with 0 liquidity.
+    ///
+    /// ### Authority
+    /// - `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.
+    ///
+    /// ### Parameters
+    /// - `bundle_index` - The bundle index that we'd like to open.
+    /// - `tick_lower_index` - The tick specifying the lower end of the position range.
+    /// - `tick_upper_index` - The tick specifying the upper end of the position range.
+    ///
+    /// #### Special Errors
+    /// - `InvalidBundleIndex` - If the provided bundle index is out of bounds.
+    /// - `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of
+    ///                        the tick-spacing in this pool.
+    pub fn open_bundled_position(
+        ctx: Context<OpenBundledPosition>,
+        bundle_index: u16,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+    ) -> Result<()> {
+        instructions::open_bundled_position::handler(
+            ctx,
+            bundle_index,
+            tick_lower_index,
+            tick_upper_index,
+        )
+    }
+
+    /// Close a bundled position in a Whirlpool.
+    ///
+    /// ### Authority
+    /// - `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.
+    ///
+    /// ### Parameters
+    /// - `bundle_index` - The bundle index that we'd like to close.
+    ///
+    /// #### Special Errors
+    /// - `InvalidBundleIndex` - If the provided bundle index is out of bounds.
+    /// - `ClosePositionNotEmpty` - The provided position account is not empty.
+    pub fn close_bundled_position(
+        ctx: Context<CloseBundledPosition>,
+        bundle_index: u16,
+    ) -> Result<()> {
+        instructions::close_bundled_position::handler(ctx, bundle_index)
     }
 
     /// Perform a two-hop swap in this Whirlpool
@@ -60,6 +575,10 @@ pub mod whirlpool {
     /// ### Authority
     /// - "token_authority" - The authority to withdraw tokens from the input token account.
     ///
+    /// ### Remaining Accounts
+    /// - Additional tick-arrays of either Whirlpool. Each is traversed, in order, after the
+    ///   tick-arrays of the Whirlpool it belongs to.
+    ///
     /// ### Parameters
     /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
     /// - `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).
@@ -80,8 +599,10 @@ pub mod whirlpool {
     /// - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
     /// - `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.
     /// - `DuplicateTwoHopPool` - Error if whirlpool one & two are the same pool.
-    pub fn two_hop_swap(
-        ctx: Context<TwoHopSwap>,
+    /// - `IntermediateTokenAmountMismatch` - Error if the output of hop one is not fully consumed as the input of hop two.
+    /// - `DifferentWhirlpoolTickArrayAccount` - A tick-array in remaining accounts does not belong to either Whirlpool.
+    pub fn two_hop_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TwoHopSwap<'info>>,
         amount: u64,
         other_amount_threshold: u64,
         amount_specified_is_input: bool,
@@ -90,6 +611,56 @@ pub mod whirlpool {
         sqrt_price_limit_one: u128,
         sqrt_price_limit_two: u128,
     ) -> Result<()> {
-        Ok(())
+        instructions::two_hop_swap::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            amount_specified_is_input,
+            a_to_b_one,
+            a_to_b_two,
+            sqrt_price_limit_one,
+            sqrt_price_limit_two,
+        )
+    }
+
+    /// Perform a multi-hop swap through an ordered route of Whirlpools.
+    ///
+    /// ### Authority
+    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    ///
+    /// ### Remaining Accounts
+    /// - The accounts of each hop, in route order: whirlpool, token_owner_account_a, token_vault_a,
+    ///   token_owner_account_b, token_vault_b, tick_array_0, tick_array_1, tick_array_2, oracle.
+    ///
+    /// ### Parameters
+    /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
+    /// - `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).
+    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the first hop.
+    /// - `hops` - The direction, sqrt-price limit and oracle PDA bump of each hop, in route order.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRouteSwapHops` - No hops were provided, or the remaining accounts do not match the number of hops.
+    /// - `DuplicateRouteSwapPool` - Error if the same whirlpool appears more than once in the route.
+    /// - `InvalidIntermediaryMint` - Error if the output mint of a hop does not equal the input mint of the next hop.
+    /// - `IntermediateTokenAmountMismatch` - Error if the output of a hop is not fully consumed as the input of the next hop.
+    /// - `DifferentWhirlpoolTickArrayAccount` - A tick-array of a hop does not belong to that hop's Whirlpool.
+    /// - `ZeroTradableAmount` - User provided parameter `amount` is 0.
+    /// - `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.
+    /// - `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.
+    /// - `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.
+    pub fn route_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RouteSwap<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        amount_specified_is_input: bool,
+        hops: Vec<RouteSwapHop>,
+    ) -> Result<()> {
+        instructions::route_swap::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            amount_specified_is_input,
+            hops,
+        )
     }
 }
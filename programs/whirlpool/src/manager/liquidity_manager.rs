@@ -0,0 +1,481 @@
+use super::{
+    position_manager::next_position_modify_liquidity_update,
+    tick_manager::{
+        next_fee_growths_inside, next_reward_growths_inside, next_tick_modify_liquidity_update,
+    },
+    whirlpool_manager::{next_whirlpool_liquidity, next_whirlpool_reward_infos},
+};
+use crate::{
+    errors::ErrorCode,
+    math::{get_amount_delta_a, get_amount_delta_b, sqrt_price_from_tick_index},
+    state::*,
+};
+use anchor_lang::prelude::{AccountLoader, *};
+
+#[derive(Debug)]
+pub struct ModifyLiquidityUpdate {
+    pub whirlpool_liquidity: u128,
+    pub tick_lower_update: TickUpdate,
+    pub tick_upper_update: TickUpdate,
+    pub reward_infos: [WhirlpoolRewardInfo; NUM_REWARDS],
+    pub position_update: PositionUpdate,
+}
+
+// Calculates state after modifying liquidity by the liquidity_delta for the given positon.
+// Fee and reward growths will also be calculated by this function.
+pub fn calculate_modify_liquidity<'info>(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    tick_array_lower: &AccountLoader<'info, TickArray>,
+    tick_array_upper: &AccountLoader<'info, TickArray>,
+    liquidity_delta: i128,
+    timestamp: u64,
+) -> Result<ModifyLiquidityUpdate> {
+    let tick_array_lower = tick_array_lower.load()?;
+    let tick_lower =
+        tick_array_lower.get_tick(position.tick_lower_index, whirlpool.tick_spacing)?;
+
+    let tick_array_upper = tick_array_upper.load()?;
+    let tick_upper =
+        tick_array_upper.get_tick(position.tick_upper_index, whirlpool.tick_spacing)?;
+
+    _calculate_modify_liquidity(
+        whirlpool,
+        position,
+        tick_lower,
+        tick_upper,
+        position.tick_lower_index,
+        position.tick_upper_index,
+        liquidity_delta,
+        timestamp,
+    )
+}
+
+// Calculates the fees and rewards earned by the given position since its last checkpoint,
+// without modifying its liquidity.
+pub fn calculate_fee_and_reward_growths<'info>(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    tick_array_lower: &AccountLoader<'info, TickArray>,
+    tick_array_upper: &AccountLoader<'info, TickArray>,
+    timestamp: u64,
+) -> Result<(PositionUpdate, [WhirlpoolRewardInfo; NUM_REWARDS])> {
+    let tick_array_lower = tick_array_lower.load()?;
+    let tick_lower =
+        tick_array_lower.get_tick(position.tick_lower_index, whirlpool.tick_spacing)?;
+
+    let tick_array_upper = tick_array_upper.load()?;
+    let tick_upper =
+        tick_array_upper.get_tick(position.tick_upper_index, whirlpool.tick_spacing)?;
+
+    // Pass in a liquidity_delta value of 0 to trigger only calculations for fee and reward growth.
+    // Calculating fees and rewards for positions with zero liquidity will result in an error.
+    let update = _calculate_modify_liquidity(
+        whirlpool,
+        position,
+        tick_lower,
+        tick_upper,
+        position.tick_lower_index,
+        position.tick_upper_index,
+        0,
+        timestamp,
+    )?;
+    Ok((update.position_update, update.reward_infos))
+}
+
+// Calculates the state changes after modifying liquidity of a whirlpool position.
+fn _calculate_modify_liquidity(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    tick_lower: &Tick,
+    tick_upper: &Tick,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    liquidity_delta: i128,
+    timestamp: u64,
+) -> Result<ModifyLiquidityUpdate> {
+    // Disallow only updating position fee and reward growth when position has zero liquidity
+    if liquidity_delta == 0 && position.liquidity == 0 {
+        return Err(ErrorCode::LiquidityZero.into());
+    }
+
+    let next_reward_infos = next_whirlpool_reward_infos(whirlpool, timestamp)?;
+
+    let next_global_liquidity = next_whirlpool_liquidity(
+        whirlpool,
+        position.tick_upper_index,
+        position.tick_lower_index,
+        liquidity_delta,
+    )?;
+
+    let tick_lower_update = next_tick_modify_liquidity_update(
+        tick_lower,
+        tick_lower_index,
+        whirlpool.tick_current_index,
+        whirlpool.fee_growth_global_a,
+        whirlpool.fee_growth_global_b,
+        &next_reward_infos,
+        liquidity_delta,
+        false,
+    )?;
+
+    let tick_upper_update = next_tick_modify_liquidity_update(
+        tick_upper,
+        tick_upper_index,
+        whirlpool.tick_current_index,
+        whirlpool.fee_growth_global_a,
+        whirlpool.fee_growth_global_b,
+        &next_reward_infos,
+        liquidity_delta,
+        true,
+    )?;
+
+    let (fee_growth_inside_a, fee_growth_inside_b) = next_fee_growths_inside(
+        whirlpool.tick_current_index,
+        tick_lower,
+        tick_lower_index,
+        tick_upper,
+        tick_upper_index,
+        whirlpool.fee_growth_global_a,
+        whirlpool.fee_growth_global_b,
+    );
+
+    let reward_growths_inside = next_reward_growths_inside(
+        whirlpool.tick_current_index,
+        tick_lower,
+        tick_lower_index,
+        tick_upper,
+        tick_upper_index,
+        &next_reward_infos,
+    );
+
+    let position_update = next_position_modify_liquidity_update(
+        position,
+        liquidity_delta,
+        fee_growth_inside_a,
+        fee_growth_inside_b,
+        &reward_growths_inside,
+    )?;
+
+    Ok(ModifyLiquidityUpdate {
+        whirlpool_liquidity: next_global_liquidity,
+        reward_infos: next_reward_infos,
+        position_update,
+        tick_lower_update,
+        tick_upper_update,
+    })
+}
+
+// Calculates the token amounts that correspond to a change in liquidity for the given position.
+// Amounts are rounded up when liquidity is added and rounded down when liquidity is removed,
+// so that the pool never pays out more than it has received.
+pub fn calculate_liquidity_token_deltas(
+    current_tick_index: i32,
+    sqrt_price: u128,
+    position: &Position,
+    liquidity_delta: i128,
+) -> Result<(u64, u64)> {
+    if liquidity_delta == 0 {
+        return Err(ErrorCode::LiquidityZero.into());
+    }
+
+    let mut delta_a: u64 = 0;
+    let mut delta_b: u64 = 0;
+
+    let liquidity: u128 = liquidity_delta.unsigned_abs();
+    let round_up = liquidity_delta > 0;
+
+    let lower_price = sqrt_price_from_tick_index(position.tick_lower_index);
+    let upper_price = sqrt_price_from_tick_index(position.tick_upper_index);
+
+    if current_tick_index < position.tick_lower_index {
+        // current tick below position
+        delta_a = get_amount_delta_a(lower_price, upper_price, liquidity, round_up)?;
+    } else if current_tick_index < position.tick_upper_index {
+        // current tick inside position
+        delta_a = get_amount_delta_a(sqrt_price, upper_price, liquidity, round_up)?;
+        delta_b = get_amount_delta_b(lower_price, sqrt_price, liquidity, round_up)?;
+    } else {
+        // current tick above position
+        delta_b = get_amount_delta_b(lower_price, upper_price, liquidity, round_up)?;
+    }
+
+    Ok((delta_a, delta_b))
+}
+
+pub fn sync_modify_liquidity_values<'info>(
+    whirlpool: &mut Whirlpool,
+    position: &mut Position,
+    tick_array_lower: &AccountLoader<'info, TickArray>,
+    tick_array_upper: &AccountLoader<'info, TickArray>,
+    modify_liquidity_update: ModifyLiquidityUpdate,
+    reward_last_updated_timestamp: u64,
+) -> Result<()> {
+    position.update(&modify_liquidity_update.position_update);
+
+    tick_array_lower.load_mut()?.update_tick(
+        position.tick_lower_index,
+        whirlpool.tick_spacing,
+        &modify_liquidity_update.tick_lower_update,
+    )?;
+
+    tick_array_upper.load_mut()?.update_tick(
+        position.tick_upper_index,
+        whirlpool.tick_spacing,
+        &modify_liquidity_update.tick_upper_update,
+    )?;
+
+    whirlpool.update_rewards_and_liquidity(
+        modify_liquidity_update.reward_infos,
+        modify_liquidity_update.whirlpool_liquidity,
+        reward_last_updated_timestamp,
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod calculate_liquidity_token_deltas_tests {
+    use super::calculate_liquidity_token_deltas;
+    use crate::{
+        errors::ErrorCode,
+        math::{sqrt_price_from_tick_index, Q64_RESOLUTION},
+        state::position_builder::PositionBuilder,
+    };
+
+    #[test]
+    fn test_zero_liquidity_delta() {
+        let position = PositionBuilder::new(-10, 10).build();
+        let result = calculate_liquidity_token_deltas(0, 1 << Q64_RESOLUTION, &position, 0);
+        assert_eq!(result.unwrap_err(), ErrorCode::LiquidityZero.into());
+    }
+
+    #[test]
+    fn test_current_tick_below_position() {
+        let position = PositionBuilder::new(-10, 10).build();
+        let sqrt_price = sqrt_price_from_tick_index(-20);
+        let (delta_a, delta_b) =
+            calculate_liquidity_token_deltas(-20, sqrt_price, &position, 1_000_000).unwrap();
+        assert!(delta_a > 0);
+        assert_eq!(delta_b, 0);
+    }
+
+    #[test]
+    fn test_current_tick_above_position() {
+        let position = PositionBuilder::new(-10, 10).build();
+        let sqrt_price = sqrt_price_from_tick_index(10);
+        // The upper tick is exclusive, so the position is fully in token b
+        let (delta_a, delta_b) =
+            calculate_liquidity_token_deltas(10, sqrt_price, &position, 1_000_000).unwrap();
+        assert_eq!(delta_a, 0);
+        assert!(delta_b > 0);
+    }
+
+    #[test]
+    fn test_current_tick_inside_position_rounding() {
+        let position = PositionBuilder::new(-10, 10).build();
+        let sqrt_price = sqrt_price_from_tick_index(0);
+        let (add_a, add_b) =
+            calculate_liquidity_token_deltas(0, sqrt_price, &position, 1_000_000).unwrap();
+        let (remove_a, remove_b) =
+            calculate_liquidity_token_deltas(0, sqrt_price, &position, -1_000_000).unwrap();
+        assert_eq!((add_a, add_b), (500, 500));
+        assert_eq!((remove_a, remove_b), (499, 499));
+    }
+}
+
+#[cfg(test)]
+mod calculate_modify_liquidity_tests {
+    use super::_calculate_modify_liquidity;
+    use crate::{
+        errors::ErrorCode,
+        math::Q64_RESOLUTION,
+        state::{
+            position_builder::PositionBuilder, whirlpool_builder::WhirlpoolBuilder, Tick,
+            TickUpdate,
+        },
+    };
+
+    fn initialized_tick(liquidity_net: i128, liquidity_gross: u128) -> Tick {
+        Tick {
+            initialized: true,
+            liquidity_net,
+            liquidity_gross,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_zero_liquidity_position_without_delta() {
+        let whirlpool = WhirlpoolBuilder::new().tick_spacing(1).build();
+        let position = PositionBuilder::new(-10, 10).build();
+        let result = _calculate_modify_liquidity(
+            &whirlpool,
+            &position,
+            &Tick::default(),
+            &Tick::default(),
+            -10,
+            10,
+            0,
+            0,
+        );
+        assert_eq!(result.unwrap_err(), ErrorCode::LiquidityZero.into());
+    }
+
+    #[test]
+    fn test_zero_delta_accrues_fees_inside_range() {
+        let whirlpool = WhirlpoolBuilder::new()
+            .tick_spacing(1)
+            .tick_current_index(0)
+            .liquidity(100)
+            .fee_growth_global_a(3 << Q64_RESOLUTION)
+            .fee_growth_global_b(5 << Q64_RESOLUTION)
+            .build();
+        let position = PositionBuilder::new(-10, 10).liquidity(100).build();
+        let mut tick_lower = initialized_tick(100, 100);
+        tick_lower.fee_growth_outside_a = 1 << Q64_RESOLUTION;
+        let update = _calculate_modify_liquidity(
+            &whirlpool,
+            &position,
+            &tick_lower,
+            &initialized_tick(-100, 100),
+            -10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+        assert_eq!(update.whirlpool_liquidity, 100);
+        assert_eq!(update.position_update.liquidity, 100);
+        assert_eq!(
+            update.position_update.fee_growth_checkpoint_a,
+            2 << Q64_RESOLUTION
+        );
+        assert_eq!(update.position_update.fee_owed_a, 200);
+        assert_eq!(
+            update.position_update.fee_growth_checkpoint_b,
+            5 << Q64_RESOLUTION
+        );
+        assert_eq!(update.position_update.fee_owed_b, 500);
+    }
+
+    #[test]
+    fn test_add_liquidity_in_range_initializes_ticks() {
+        let whirlpool = WhirlpoolBuilder::new()
+            .tick_spacing(1)
+            .tick_current_index(0)
+            .liquidity(1000)
+            .fee_growth_global_a(1 << Q64_RESOLUTION)
+            .fee_growth_global_b(2 << Q64_RESOLUTION)
+            .build();
+        let position = PositionBuilder::new(-10, 10).build();
+        let update = _calculate_modify_liquidity(
+            &whirlpool,
+            &position,
+            &Tick::default(),
+            &Tick::default(),
+            -10,
+            10,
+            100,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(update.whirlpool_liquidity, 1100);
+        assert_eq!(update.position_update.liquidity, 100);
+        // All prior growth is assumed to have happened below the lower tick
+        assert_eq!(
+            update.tick_lower_update,
+            TickUpdate {
+                initialized: true,
+                liquidity_net: 100,
+                liquidity_gross: 100,
+                fee_growth_outside_a: 1 << Q64_RESOLUTION,
+                fee_growth_outside_b: 2 << Q64_RESOLUTION,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            update.tick_upper_update,
+            TickUpdate {
+                initialized: true,
+                liquidity_net: -100,
+                liquidity_gross: 100,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_add_liquidity_out_of_range_keeps_whirlpool_liquidity() {
+        let whirlpool = WhirlpoolBuilder::new()
+            .tick_spacing(1)
+            .tick_current_index(20)
+            .liquidity(1000)
+            .build();
+        let position = PositionBuilder::new(-10, 10).build();
+        let update = _calculate_modify_liquidity(
+            &whirlpool,
+            &position,
+            &Tick::default(),
+            &Tick::default(),
+            -10,
+            10,
+            100,
+            0,
+        )
+        .unwrap();
+        assert_eq!(update.whirlpool_liquidity, 1000);
+    }
+
+    #[test]
+    fn test_remove_all_liquidity_uninitializes_ticks() {
+        let whirlpool = WhirlpoolBuilder::new()
+            .tick_spacing(1)
+            .tick_current_index(0)
+            .liquidity(100)
+            .build();
+        let position = PositionBuilder::new(-10, 10).liquidity(100).build();
+        let update = _calculate_modify_liquidity(
+            &whirlpool,
+            &position,
+            &initialized_tick(100, 100),
+            &initialized_tick(-100, 100),
+            -10,
+            10,
+            -100,
+            0,
+        )
+        .unwrap();
+        assert_eq!(update.whirlpool_liquidity, 0);
+        assert_eq!(update.position_update.liquidity, 0);
+        assert_eq!(update.tick_lower_update, TickUpdate::default());
+        assert_eq!(update.tick_upper_update, TickUpdate::default());
+    }
+
+    #[test]
+    fn test_remove_partial_liquidity_keeps_ticks_initialized() {
+        let whirlpool = WhirlpoolBuilder::new()
+            .tick_spacing(1)
+            .tick_current_index(0)
+            .liquidity(300)
+            .build();
+        let position = PositionBuilder::new(-10, 10).liquidity(100).build();
+        let update = _calculate_modify_liquidity(
+            &whirlpool,
+            &position,
+            &initialized_tick(300, 300),
+            &initialized_tick(-300, 300),
+            -10,
+            10,
+            -100,
+            0,
+        )
+        .unwrap();
+        assert_eq!(update.whirlpool_liquidity, 200);
+        assert_eq!(update.tick_lower_update.liquidity_net, 200);
+        assert_eq!(update.tick_lower_update.liquidity_gross, 200);
+        assert_eq!(update.tick_upper_update.liquidity_net, -200);
+        assert_eq!(update.tick_upper_update.liquidity_gross, 200);
+    }
+}
@@ -1,2 +1,5 @@
+pub mod liquidity_manager;
+pub mod position_manager;
 pub mod swap_manager;
 pub mod tick_manager;
+pub mod whirlpool_manager;
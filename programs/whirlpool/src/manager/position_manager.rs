@@ -0,0 +1,158 @@
+use crate::{
+    errors::ErrorCode,
+    math::{add_liquidity_delta, checked_mul_shift_right},
+    state::{Position, PositionUpdate, NUM_REWARDS},
+};
+
+// Calculates the next position state after accruing the fees and rewards earned inside the
+// position's range since the last checkpoint, and applying the liquidity delta.
+pub fn next_position_modify_liquidity_update(
+    position: &Position,
+    liquidity_delta: i128,
+    fee_growth_inside_a: u128,
+    fee_growth_inside_b: u128,
+    reward_growths_inside: &[u128; NUM_REWARDS],
+) -> Result<PositionUpdate, ErrorCode> {
+    let mut update = PositionUpdate::default();
+
+    // Calculate fee deltas.
+    // If fee deltas overflow, default to a zero value. This means the position loses
+    // all fees earned since the last time the position was modified or fees collected.
+    let growth_delta_a = fee_growth_inside_a.wrapping_sub(position.fee_growth_checkpoint_a);
+    let fee_delta_a = checked_mul_shift_right(position.liquidity, growth_delta_a).unwrap_or(0);
+
+    let growth_delta_b = fee_growth_inside_b.wrapping_sub(position.fee_growth_checkpoint_b);
+    let fee_delta_b = checked_mul_shift_right(position.liquidity, growth_delta_b).unwrap_or(0);
+
+    update.fee_growth_checkpoint_a = fee_growth_inside_a;
+    update.fee_growth_checkpoint_b = fee_growth_inside_b;
+
+    // Overflows allowed. Must collect fees owed before overflow.
+    update.fee_owed_a = position.fee_owed_a.wrapping_add(fee_delta_a);
+    update.fee_owed_b = position.fee_owed_b.wrapping_add(fee_delta_b);
+
+    for (i, reward_growth_inside) in reward_growths_inside.iter().enumerate() {
+        let curr_reward_info = position.reward_infos[i];
+
+        // Calculate reward delta.
+        // If reward delta overflows, default to a zero value. This means the position loses all
+        // rewards earned since the last time the position was modified or rewards were collected.
+        let reward_growth_delta =
+            reward_growth_inside.wrapping_sub(curr_reward_info.growth_inside_checkpoint);
+        let amount_owed_delta =
+            checked_mul_shift_right(position.liquidity, reward_growth_delta).unwrap_or(0);
+
+        update.reward_infos[i].growth_inside_checkpoint = *reward_growth_inside;
+
+        // Overflows allowed. Must collect rewards owed before overflow.
+        update.reward_infos[i].amount_owed =
+            curr_reward_info.amount_owed.wrapping_add(amount_owed_delta);
+    }
+
+    update.liquidity = add_liquidity_delta(position.liquidity, liquidity_delta)?;
+
+    Ok(update)
+}
+
+#[cfg(test)]
+mod position_manager_unit_tests {
+    use super::next_position_modify_liquidity_update;
+    use crate::{
+        errors::ErrorCode,
+        math::Q64_RESOLUTION,
+        state::{position_builder::PositionBuilder, PositionRewardInfo, PositionUpdate},
+    };
+
+    #[test]
+    fn test_next_position_modify_liquidity_update_no_growth() {
+        let position = PositionBuilder::new(-10, 10).liquidity(100).build();
+        let update =
+            next_position_modify_liquidity_update(&position, 50, 0, 0, &[0, 0, 0]).unwrap();
+        assert_eq!(
+            update,
+            PositionUpdate {
+                liquidity: 150,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_next_position_modify_liquidity_update_fees_accrued() {
+        let position = PositionBuilder::new(-10, 10)
+            .liquidity(100)
+            .fee_growth_checkpoint_a(1 << Q64_RESOLUTION)
+            .fee_owed_a(10)
+            .fee_growth_checkpoint_b(2 << Q64_RESOLUTION)
+            .fee_owed_b(20)
+            .build();
+        let update = next_position_modify_liquidity_update(
+            &position,
+            -100,
+            3 << Q64_RESOLUTION,
+            5 << Q64_RESOLUTION,
+            &[0, 0, 0],
+        )
+        .unwrap();
+        assert_eq!(update.liquidity, 0);
+        assert_eq!(update.fee_growth_checkpoint_a, 3 << Q64_RESOLUTION);
+        assert_eq!(update.fee_owed_a, 210);
+        assert_eq!(update.fee_growth_checkpoint_b, 5 << Q64_RESOLUTION);
+        assert_eq!(update.fee_owed_b, 320);
+    }
+
+    #[test]
+    fn test_next_position_modify_liquidity_update_fee_growth_wraps() {
+        let position = PositionBuilder::new(-10, 10)
+            .liquidity(100)
+            .fee_growth_checkpoint_a(u128::MAX - (1 << Q64_RESOLUTION) + 1)
+            .build();
+        let update =
+            next_position_modify_liquidity_update(&position, 0, 1 << Q64_RESOLUTION, 0, &[0, 0, 0])
+                .unwrap();
+        assert_eq!(update.fee_owed_a, 200);
+    }
+
+    #[test]
+    fn test_next_position_modify_liquidity_update_rewards_accrued() {
+        let position = PositionBuilder::new(-10, 10)
+            .liquidity(100)
+            .reward_info(
+                0,
+                PositionRewardInfo {
+                    growth_inside_checkpoint: 1 << Q64_RESOLUTION,
+                    amount_owed: 5,
+                },
+            )
+            .build();
+        let update = next_position_modify_liquidity_update(
+            &position,
+            0,
+            0,
+            0,
+            &[2 << Q64_RESOLUTION, 1 << Q64_RESOLUTION, 0],
+        )
+        .unwrap();
+        assert_eq!(
+            update.reward_infos,
+            [
+                PositionRewardInfo {
+                    growth_inside_checkpoint: 2 << Q64_RESOLUTION,
+                    amount_owed: 105,
+                },
+                PositionRewardInfo {
+                    growth_inside_checkpoint: 1 << Q64_RESOLUTION,
+                    amount_owed: 100,
+                },
+                PositionRewardInfo::default(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_position_modify_liquidity_update_liquidity_underflow() {
+        let position = PositionBuilder::new(-10, 10).liquidity(100).build();
+        let result = next_position_modify_liquidity_update(&position, -101, 0, 0, &[0, 0, 0]);
+        assert_eq!(result.unwrap_err(), ErrorCode::LiquidityUnderflow);
+    }
+}
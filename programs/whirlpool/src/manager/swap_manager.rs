@@ -1,7 +1,16 @@
 // use solana_program::msg;
 
-use crate::{errors::ErrorCode, math::*, state::*, util::SwapTickSequence};
+use crate::{
+    errors::ErrorCode,
+    manager::{
+        tick_manager::next_tick_cross_update, whirlpool_manager::next_whirlpool_reward_infos,
+    },
+    math::*,
+    state::*,
+    util::SwapTickSequence,
+};
 use anchor_lang::prelude::*;
+use std::convert::TryInto;
 
 #[derive(Debug)]
 pub struct PostSwapUpdate {
@@ -10,16 +19,25 @@ pub struct PostSwapUpdate {
     pub next_liquidity: u128,
     pub next_tick_index: i32,
     pub next_sqrt_price: u128,
+    pub next_fee_growth_global: u128,
+    pub next_reward_infos: [WhirlpoolRewardInfo; NUM_REWARDS],
+    pub next_protocol_fee: u64,
+    // Total fee charged on the input token, including the protocol fee
+    pub fee_amount: u64,
+    // Number of initialized ticks crossed during the swap
+    pub ticks_crossed: u32,
+    // Index of the furthest tick array in the sequence that the swap reached
+    pub last_tick_array_index: usize,
 }
 
 pub fn swap(
     whirlpool: &Whirlpool,
-    swap_tick_sequence: &SwapTickSequence,
+    swap_tick_sequence: &mut SwapTickSequence,
     amount: u64,
     sqrt_price_limit: u128,
     amount_specified_is_input: bool,
     a_to_b: bool,
-    _timestamp: u64,
+    timestamp: u64,
 ) -> Result<PostSwapUpdate> {
     if sqrt_price_limit < MIN_SQRT_PRICE_X64 || sqrt_price_limit > MAX_SQRT_PRICE_X64 {
         return Err(ErrorCode::SqrtPriceOutOfBounds.into());
@@ -35,8 +53,12 @@ pub fn swap(
         return Err(ErrorCode::ZeroTradableAmount.into());
     }
 
+    // Update the global reward growth which increases as a function of time.
+    let next_reward_infos = next_whirlpool_reward_infos(whirlpool, timestamp)?;
+
     let tick_spacing = whirlpool.tick_spacing;
     let fee_rate = whirlpool.fee_rate;
+    let protocol_fee_rate = whirlpool.protocol_fee_rate;
 
     let mut amount_remaining: u64 = amount;
     let mut amount_calculated: u64 = 0;
@@ -44,6 +66,15 @@ pub fn swap(
     let mut curr_tick_index = whirlpool.tick_current_index;
     let mut curr_liquidity = whirlpool.liquidity;
     let mut curr_array_index: usize = 0;
+    let mut curr_protocol_fee: u64 = 0;
+    let mut curr_fee_amount: u64 = 0;
+    let mut ticks_crossed: u32 = 0;
+    let mut last_tick_array_index: usize = 0;
+    let mut curr_fee_growth_global_input = if a_to_b {
+        whirlpool.fee_growth_global_a
+    } else {
+        whirlpool.fee_growth_global_b
+    };
 
     while amount_remaining > 0 && sqrt_price_limit != curr_sqrt_price {
         let (next_array_index, next_tick_index) = swap_tick_sequence
@@ -54,6 +85,8 @@ pub fn swap(
                 curr_array_index,
             )?;
 
+        last_tick_array_index = last_tick_array_index.max(next_array_index);
+
         let (next_tick_sqrt_price, sqrt_price_target) =
             get_next_sqrt_prices(next_tick_index, sqrt_price_limit, a_to_b);
 
@@ -90,15 +123,47 @@ pub fn swap(
                 .checked_add(swap_computation.fee_amount)
                 .ok_or(ErrorCode::AmountCalcOverflow)?;
         }
+
+        let (next_protocol_fee, next_fee_growth_global_input) = calculate_fees(
+            swap_computation.fee_amount,
+            protocol_fee_rate,
+            curr_liquidity,
+            curr_protocol_fee,
+            curr_fee_growth_global_input,
+        );
+        curr_protocol_fee = next_protocol_fee;
+        curr_fee_growth_global_input = next_fee_growth_global_input;
+        curr_fee_amount += swap_computation.fee_amount;
+
         if swap_computation.next_price == next_tick_sqrt_price {
             let (next_tick, next_tick_initialized) = swap_tick_sequence
                 .get_tick(next_array_index, next_tick_index, tick_spacing)
                 .map_or_else(|_| (None, false), |tick| (Some(tick), tick.initialized));
 
             if next_tick_initialized {
-                let next_liquidity = calculate_update(&next_tick.unwrap(), a_to_b, curr_liquidity)?;
+                let (fee_growth_global_a, fee_growth_global_b) = if a_to_b {
+                    (curr_fee_growth_global_input, whirlpool.fee_growth_global_b)
+                } else {
+                    (whirlpool.fee_growth_global_a, curr_fee_growth_global_input)
+                };
+
+                let (update, next_liquidity) = calculate_update(
+                    next_tick.unwrap(),
+                    a_to_b,
+                    curr_liquidity,
+                    fee_growth_global_a,
+                    fee_growth_global_b,
+                    &next_reward_infos,
+                )?;
 
                 curr_liquidity = next_liquidity;
+                ticks_crossed += 1;
+                swap_tick_sequence.update_tick(
+                    next_array_index,
+                    next_tick_index,
+                    tick_spacing,
+                    &update,
+                )?;
             }
 
             let tick_offset = swap_tick_sequence.get_tick_offset(
@@ -153,10 +218,57 @@ pub fn swap(
         next_liquidity: curr_liquidity,
         next_tick_index: curr_tick_index,
         next_sqrt_price: curr_sqrt_price,
+        next_fee_growth_global: curr_fee_growth_global_input,
+        next_reward_infos,
+        next_protocol_fee: curr_protocol_fee,
+        fee_amount: curr_fee_amount,
+        ticks_crossed,
+        last_tick_array_index,
     })
 }
 
-fn calculate_update(tick: &Tick, a_to_b: bool, liquidity: u128) -> Result<u128> {
+// Splits the fee of a single swap step into the protocol's share and the LPs' share.
+// The LP share is accrued to the global fee growth as a Q64.64 amount per unit of liquidity.
+fn calculate_fees(
+    fee_amount: u64,
+    protocol_fee_rate: u16,
+    curr_liquidity: u128,
+    curr_protocol_fee: u64,
+    curr_fee_growth_global_input: u128,
+) -> (u64, u128) {
+    let mut next_protocol_fee = curr_protocol_fee;
+    let mut next_fee_growth_global_input = curr_fee_growth_global_input;
+    let mut global_fee = fee_amount;
+    if protocol_fee_rate > 0 {
+        let delta = calculate_protocol_fee(global_fee, protocol_fee_rate);
+        global_fee -= delta;
+        next_protocol_fee = next_protocol_fee.wrapping_add(delta);
+    }
+
+    // Fees collected while there is no active liquidity are not attributed to any LP
+    if let Some(fee_growth_delta) =
+        ((global_fee as u128) << Q64_RESOLUTION).checked_div(curr_liquidity)
+    {
+        next_fee_growth_global_input = next_fee_growth_global_input.wrapping_add(fee_growth_delta);
+    }
+    (next_protocol_fee, next_fee_growth_global_input)
+}
+
+fn calculate_protocol_fee(global_fee: u64, protocol_fee_rate: u16) -> u64 {
+    // protocol_fee_rate is capped at MAX_PROTOCOL_FEE_RATE, so the result always fits in a u64
+    ((global_fee as u128) * (protocol_fee_rate as u128) / PROTOCOL_FEE_RATE_MUL_VALUE)
+        .try_into()
+        .unwrap()
+}
+
+fn calculate_update(
+    tick: &Tick,
+    a_to_b: bool,
+    liquidity: u128,
+    fee_growth_global_a: u128,
+    fee_growth_global_b: u128,
+    reward_infos: &[WhirlpoolRewardInfo; NUM_REWARDS],
+) -> Result<(TickUpdate, u128)> {
     // Use updated fee_growth for crossing tick
     // Use -liquidity_net if going left, +liquidity_net going right
     let signed_liquidity_net = if a_to_b {
@@ -165,8 +277,13 @@ fn calculate_update(tick: &Tick, a_to_b: bool, liquidity: u128) -> Result<u128>
         tick.liquidity_net
     };
 
+    let update =
+        next_tick_cross_update(tick, fee_growth_global_a, fee_growth_global_b, reward_infos)?;
+
     // Update the global liquidity to reflect the new current tick
-    Ok(add_liquidity_delta(liquidity, signed_liquidity_net)?)
+    let next_liquidity = add_liquidity_delta(liquidity, signed_liquidity_net)?;
+
+    Ok((update, next_liquidity))
 }
 
 fn get_next_sqrt_prices(
@@ -182,3 +299,44 @@ fn get_next_sqrt_prices(
     };
     (next_tick_price, next_sqrt_price_limit)
 }
+
+#[cfg(test)]
+mod calculate_fees_tests {
+    use super::*;
+
+    #[test]
+    fn test_no_protocol_fee() {
+        let (protocol_fee, fee_growth) = calculate_fees(1000, 0, 1000, 0, 0);
+        assert_eq!(protocol_fee, 0);
+        assert_eq!(fee_growth, 1 << Q64_RESOLUTION);
+    }
+
+    #[test]
+    fn test_protocol_fee_split() {
+        // 3% protocol fee rate
+        let (protocol_fee, fee_growth) = calculate_fees(1000, 300, 970, 5, 7);
+        assert_eq!(protocol_fee, 35);
+        assert_eq!(fee_growth, 7 + (1 << Q64_RESOLUTION));
+    }
+
+    #[test]
+    fn test_max_protocol_fee_rate() {
+        let (protocol_fee, fee_growth) =
+            calculate_fees(u64::MAX, MAX_PROTOCOL_FEE_RATE, u128::MAX, 0, 0);
+        assert_eq!(protocol_fee, u64::MAX / 4);
+        assert_eq!(fee_growth, 0);
+    }
+
+    #[test]
+    fn test_zero_liquidity_keeps_fee_growth() {
+        let (protocol_fee, fee_growth) = calculate_fees(1000, 1000, 0, 0, 100);
+        assert_eq!(protocol_fee, 100);
+        assert_eq!(fee_growth, 100);
+    }
+
+    #[test]
+    fn test_fee_growth_wraps() {
+        let (_, fee_growth) = calculate_fees(1, 0, 1, 0, u128::MAX);
+        assert_eq!(fee_growth, (1 << Q64_RESOLUTION) - 1);
+    }
+}
@@ -8,12 +8,23 @@ pub fn next_tick_cross_update(
     tick: &Tick,
     fee_growth_global_a: u128,
     fee_growth_global_b: u128,
+    reward_infos: &[WhirlpoolRewardInfo; NUM_REWARDS],
 ) -> Result<TickUpdate, ErrorCode> {
     let mut update = TickUpdate::from(tick);
 
     update.fee_growth_outside_a = fee_growth_global_a.wrapping_sub(tick.fee_growth_outside_a);
     update.fee_growth_outside_b = fee_growth_global_b.wrapping_sub(tick.fee_growth_outside_b);
 
+    for (i, reward_info) in reward_infos.iter().enumerate() {
+        if !reward_info.initialized() {
+            continue;
+        }
+
+        update.reward_growths_outside[i] = reward_info
+            .growth_global_x64
+            .wrapping_sub(tick.reward_growths_outside[i]);
+    }
+
     Ok(update)
 }
 
@@ -177,3 +188,81 @@ pub fn next_reward_growths_inside(
 
     reward_growths_inside
 }
+
+#[cfg(test)]
+mod next_tick_cross_update_tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+
+    fn initialized_reward_info(growth_global_x64: u128) -> WhirlpoolRewardInfo {
+        WhirlpoolRewardInfo {
+            mint: Pubkey::new_unique(),
+            growth_global_x64,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_fee_growths_flipped() {
+        let tick = Tick {
+            initialized: true,
+            liquidity_net: 100,
+            liquidity_gross: 100,
+            fee_growth_outside_a: 300,
+            fee_growth_outside_b: 1000,
+            ..Default::default()
+        };
+        let update = next_tick_cross_update(
+            &tick,
+            500,
+            1500,
+            &[WhirlpoolRewardInfo::default(); NUM_REWARDS],
+        )
+        .unwrap();
+        assert_eq!(
+            update,
+            TickUpdate {
+                initialized: true,
+                liquidity_net: 100,
+                liquidity_gross: 100,
+                fee_growth_outside_a: 200,
+                fee_growth_outside_b: 500,
+                reward_growths_outside: [0; NUM_REWARDS],
+            }
+        );
+    }
+
+    #[test]
+    fn test_fee_growths_wrap() {
+        let tick = Tick {
+            initialized: true,
+            fee_growth_outside_a: 10,
+            fee_growth_outside_b: 20,
+            ..Default::default()
+        };
+        let update =
+            next_tick_cross_update(&tick, 5, 0, &[WhirlpoolRewardInfo::default(); NUM_REWARDS])
+                .unwrap();
+        assert_eq!(update.fee_growth_outside_a, u128::MAX - 4);
+        assert_eq!(update.fee_growth_outside_b, u128::MAX - 19);
+    }
+
+    #[test]
+    fn test_only_initialized_reward_growths_flipped() {
+        let tick = Tick {
+            initialized: true,
+            reward_growths_outside: [100, 200, 300],
+            ..Default::default()
+        };
+        let reward_infos = [
+            initialized_reward_info(500),
+            WhirlpoolRewardInfo {
+                growth_global_x64: 1000,
+                ..Default::default()
+            },
+            initialized_reward_info(350),
+        ];
+        let update = next_tick_cross_update(&tick, 0, 0, &reward_infos).unwrap();
+        assert_eq!(update.reward_growths_outside, [400, 200, 50]);
+    }
+}
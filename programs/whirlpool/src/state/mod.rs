@@ -1,5 +1,6 @@
 pub mod config;
 pub mod fee_tier;
+pub mod oracle;
 pub mod position;
 pub mod position_bundle;
 pub mod tick;
@@ -8,6 +9,7 @@ pub mod whirlpool;
 pub use self::whirlpool::*;
 pub use config::*;
 pub use fee_tier::*;
+pub use oracle::*;
 pub use position::*;
 pub use position_bundle::*;
 pub use tick::*;
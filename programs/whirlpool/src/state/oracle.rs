@@ -0,0 +1,447 @@
+use crate::errors::ErrorCode;
+use crate::math::{checked_mul_div, Q64_RESOLUTION};
+use anchor_lang::prelude::*;
+
+// The Oracle account is allocated with all observation slots up-front so that growing the
+// cardinality never requires a realloc. Sized to stay below the 10KB CPI allocation limit.
+pub const MAX_OBSERVATIONS: u16 = 300;
+pub const MAX_OBSERVATIONS_USIZE: usize = 300;
+
+#[zero_copy(unsafe)]
+#[repr(packed)]
+#[derive(Default, Debug, PartialEq)]
+pub struct Observation {
+    // Total 33 bytes
+    pub timestamp: u64,       // 8
+    pub tick_cumulative: i64, // 8
+    // Q64.64
+    pub seconds_per_liquidity_cumulative_x64: u128, // 16
+    pub initialized: bool,                          // 1
+}
+
+impl Observation {
+    pub const LEN: usize = 33;
+
+    /// Accumulate the tick and seconds-per-liquidity values from this observation up until the
+    /// given timestamp, assuming the tick and liquidity stayed constant over the interval.
+    ///
+    /// # Parameters
+    /// - `timestamp` - The timestamp of the new observation. Must not precede this observation.
+    /// - `tick_current_index` - The tick index in effect since this observation
+    /// - `liquidity` - The liquidity in effect since this observation
+    pub fn transform(&self, timestamp: u64, tick_current_index: i32, liquidity: u128) -> Self {
+        let delta = timestamp - self.timestamp;
+        // Liquidity of zero is treated as one so the seconds-per-liquidity value keeps accruing.
+        let liquidity = liquidity.max(1);
+
+        Observation {
+            timestamp,
+            tick_cumulative: self
+                .tick_cumulative
+                .wrapping_add((tick_current_index as i64).wrapping_mul(delta as i64)),
+            seconds_per_liquidity_cumulative_x64: self
+                .seconds_per_liquidity_cumulative_x64
+                .wrapping_add(((delta as u128) << Q64_RESOLUTION) / liquidity),
+            initialized: true,
+        }
+    }
+}
+
+#[account(zero_copy(unsafe))]
+#[repr(packed)]
+pub struct Oracle {
+    pub whirlpool: Pubkey,                 // 32
+    pub observation_index: u16,            // 2
+    pub observation_cardinality: u16,      // 2
+    pub observation_cardinality_next: u16, // 2
+    pub observations: [Observation; MAX_OBSERVATIONS_USIZE],
+}
+
+impl Default for Oracle {
+    #[inline]
+    fn default() -> Oracle {
+        Oracle {
+            whirlpool: Pubkey::default(),
+            observation_index: 0,
+            observation_cardinality: 0,
+            observation_cardinality_next: 0,
+            observations: [Observation::default(); MAX_OBSERVATIONS_USIZE],
+        }
+    }
+}
+
+impl Oracle {
+    pub const LEN: usize = 8 + 38 + (Observation::LEN * MAX_OBSERVATIONS_USIZE);
+
+    /// Initialize the oracle with a single observation at the given timestamp.
+    pub fn initialize(&mut self, whirlpool: Pubkey, timestamp: u64) {
+        self.whirlpool = whirlpool;
+        self.observation_index = 0;
+        self.observation_cardinality = 1;
+        self.observation_cardinality_next = 1;
+        self.observations[0] = Observation {
+            timestamp,
+            initialized: true,
+            ..Default::default()
+        };
+    }
+
+    /// Increase the number of observations the oracle will store. The new slots are only
+    /// used once the ring buffer wraps around to them.
+    ///
+    /// # Errors
+    /// - `InvalidOracleCardinality`: - The new cardinality does not exceed the current one or exceeds MAX_OBSERVATIONS.
+    pub fn grow(&mut self, observation_cardinality_next: u16) -> Result<()> {
+        if observation_cardinality_next <= self.observation_cardinality_next
+            || observation_cardinality_next > MAX_OBSERVATIONS
+        {
+            return Err(ErrorCode::InvalidOracleCardinality.into());
+        }
+        self.observation_cardinality_next = observation_cardinality_next;
+        Ok(())
+    }
+
+    /// Write an observation for the state in effect since the latest observation.
+    /// At most one observation is written per timestamp, subsequent writes are ignored.
+    ///
+    /// # Parameters
+    /// - `timestamp` - The current timestamp
+    /// - `tick_current_index` - The whirlpool tick index prior to any update at this timestamp
+    /// - `liquidity` - The whirlpool liquidity prior to any update at this timestamp
+    pub fn write(&mut self, timestamp: u64, tick_current_index: i32, liquidity: u128) {
+        let last = self.observations[self.observation_index as usize];
+        if last.timestamp >= timestamp {
+            return;
+        }
+
+        // Only start using the grown slots when we are at the end of the current ring buffer
+        if self.observation_cardinality_next > self.observation_cardinality
+            && self.observation_index == self.observation_cardinality - 1
+        {
+            self.observation_cardinality = self.observation_cardinality_next;
+        }
+
+        self.observation_index = (self.observation_index + 1) % self.observation_cardinality;
+        self.observations[self.observation_index as usize] =
+            last.transform(timestamp, tick_current_index, liquidity);
+    }
+
+    /// Get the cumulative tick and seconds-per-liquidity values `seconds_ago` seconds before
+    /// the given timestamp. The time-weighted average tick between two points in time is the
+    /// difference of their tick cumulatives divided by the elapsed seconds.
+    ///
+    /// # Parameters
+    /// - `timestamp` - The current timestamp
+    /// - `seconds_ago` - How far back from the current timestamp to look
+    /// - `tick_current_index` - The current whirlpool tick index
+    /// - `liquidity` - The current whirlpool liquidity
+    ///
+    /// # Returns
+    /// - `(i64, u128)`: The tick cumulative and seconds-per-liquidity cumulative at the target time
+    /// - `OracleObservationTooOld`: - The target time precedes the oldest stored observation.
+    pub fn observe(
+        &self,
+        timestamp: u64,
+        seconds_ago: u64,
+        tick_current_index: i32,
+        liquidity: u128,
+    ) -> Result<(i64, u128)> {
+        let target = match timestamp.checked_sub(seconds_ago) {
+            Some(target) => target,
+            None => return Err(ErrorCode::OracleObservationTooOld.into()),
+        };
+
+        let newest = self.observations[self.observation_index as usize];
+        if newest.timestamp <= target {
+            let observation = if newest.timestamp == target {
+                newest
+            } else {
+                newest.transform(target, tick_current_index, liquidity)
+            };
+            return Ok((
+                observation.tick_cumulative,
+                observation.seconds_per_liquidity_cumulative_x64,
+            ));
+        }
+
+        let (before, after) = self.get_surrounding_observations(target)?;
+        if before.timestamp == target {
+            return Ok((
+                before.tick_cumulative,
+                before.seconds_per_liquidity_cumulative_x64,
+            ));
+        }
+        if after.timestamp == target {
+            return Ok((
+                after.tick_cumulative,
+                after.seconds_per_liquidity_cumulative_x64,
+            ));
+        }
+
+        // Interpolate between the two observations surrounding the target. Multiply before
+        // dividing so the per-second rate is not truncated. The target delta is smaller than
+        // the observation delta, so the results fit back into the original types.
+        let observation_delta = after.timestamp - before.timestamp;
+        let target_delta = target - before.timestamp;
+        let tick_cumulative_delta = after.tick_cumulative.wrapping_sub(before.tick_cumulative)
+            as i128
+            * target_delta as i128
+            / observation_delta as i128;
+        let tick_cumulative = before
+            .tick_cumulative
+            .wrapping_add(tick_cumulative_delta as i64);
+        let seconds_per_liquidity_cumulative_x64 = before
+            .seconds_per_liquidity_cumulative_x64
+            .wrapping_add(checked_mul_div(
+                after
+                    .seconds_per_liquidity_cumulative_x64
+                    .wrapping_sub(before.seconds_per_liquidity_cumulative_x64),
+                target_delta as u128,
+                observation_delta as u128,
+            )?);
+
+        Ok((tick_cumulative, seconds_per_liquidity_cumulative_x64))
+    }
+
+    // Binary search for the observations at or immediately before and after the target.
+    // The target must be older than the newest observation.
+    fn get_surrounding_observations(&self, target: u64) -> Result<(Observation, Observation)> {
+        let cardinality = self.observation_cardinality as usize;
+        let index = self.observation_index as usize;
+
+        // The slot after the newest observation holds the oldest one, unless the ring buffer
+        // has not been filled yet, in which case the oldest observation is at index 0.
+        let mut oldest_index = (index + 1) % cardinality;
+        if !self.observations[oldest_index].initialized {
+            oldest_index = 0;
+        }
+        if self.observations[oldest_index].timestamp > target {
+            return Err(ErrorCode::OracleObservationTooOld.into());
+        }
+
+        let mut left = oldest_index;
+        let mut right = if oldest_index == 0 {
+            index
+        } else {
+            oldest_index + cardinality - 1
+        };
+
+        loop {
+            let mid = (left + right) / 2;
+            let before = self.observations[mid % cardinality];
+            let after = self.observations[(mid + 1) % cardinality];
+
+            if before.timestamp <= target {
+                if target <= after.timestamp {
+                    return Ok((before, after));
+                }
+                left = mid + 1;
+            } else {
+                right = mid - 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod oracle_tests {
+    use super::*;
+    use crate::math::Q64_RESOLUTION;
+
+    fn initialized_oracle(timestamp: u64) -> Oracle {
+        let mut oracle = Oracle::default();
+        oracle.initialize(Pubkey::new_unique(), timestamp);
+        oracle
+    }
+
+    #[test]
+    fn test_initialize() {
+        let oracle = initialized_oracle(100);
+        assert_eq!({ oracle.observation_index }, 0);
+        assert_eq!({ oracle.observation_cardinality }, 1);
+        assert_eq!({ oracle.observation_cardinality_next }, 1);
+        assert_eq!(
+            oracle.observations[0],
+            Observation {
+                timestamp: 100,
+                initialized: true,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(Oracle::LEN, 9946);
+        assert_eq!(Oracle::LEN, 8 + std::mem::size_of::<Oracle>());
+    }
+
+    #[test]
+    fn test_transform_accumulates() {
+        let observation = Observation {
+            timestamp: 10,
+            tick_cumulative: 5,
+            seconds_per_liquidity_cumulative_x64: 0,
+            initialized: true,
+        };
+        let next = observation.transform(20, -3, 2);
+        assert_eq!({ next.timestamp }, 20);
+        assert_eq!({ next.tick_cumulative }, 5 - 30);
+        assert_eq!(
+            { next.seconds_per_liquidity_cumulative_x64 },
+            5 << Q64_RESOLUTION
+        );
+    }
+
+    #[test]
+    fn test_transform_zero_liquidity() {
+        let observation = Observation {
+            timestamp: 10,
+            initialized: true,
+            ..Default::default()
+        };
+        let next = observation.transform(12, 0, 0);
+        assert_eq!(
+            { next.seconds_per_liquidity_cumulative_x64 },
+            2 << Q64_RESOLUTION
+        );
+    }
+
+    #[test]
+    fn test_write_once_per_timestamp() {
+        let mut oracle = initialized_oracle(100);
+        oracle.grow(3).unwrap();
+        oracle.write(100, 10, 1);
+        assert_eq!({ oracle.observation_index }, 0);
+
+        oracle.write(110, 10, 1);
+        oracle.write(110, 50, 1);
+        assert_eq!({ oracle.observation_index }, 1);
+        assert_eq!({ oracle.observations[1].tick_cumulative }, 100);
+    }
+
+    #[test]
+    fn test_write_without_grow_overwrites_single_slot() {
+        let mut oracle = initialized_oracle(100);
+        oracle.write(110, 10, 1);
+        oracle.write(120, 20, 1);
+        assert_eq!({ oracle.observation_index }, 0);
+        assert_eq!({ oracle.observation_cardinality }, 1);
+        assert_eq!({ oracle.observations[0].timestamp }, 120);
+        assert_eq!({ oracle.observations[0].tick_cumulative }, 300);
+    }
+
+    #[test]
+    fn test_grow_applies_at_end_of_ring_buffer() {
+        let mut oracle = initialized_oracle(0);
+        oracle.grow(2).unwrap();
+        oracle.write(1, 0, 1);
+        assert_eq!({ oracle.observation_cardinality }, 2);
+        assert_eq!({ oracle.observation_index }, 1);
+
+        oracle.grow(4).unwrap();
+        oracle.write(2, 0, 1);
+        // The index was at the end of the buffer, so the grown slots are used immediately
+        assert_eq!({ oracle.observation_cardinality }, 4);
+        assert_eq!({ oracle.observation_index }, 2);
+        oracle.write(3, 0, 1);
+        oracle.write(4, 0, 1);
+        assert_eq!({ oracle.observation_index }, 0);
+        assert_eq!({ oracle.observations[0].timestamp }, 4);
+    }
+
+    #[test]
+    fn test_grow_invalid() {
+        let mut oracle = initialized_oracle(0);
+        oracle.grow(5).unwrap();
+        assert_eq!(
+            oracle.grow(5).unwrap_err(),
+            ErrorCode::InvalidOracleCardinality.into()
+        );
+        assert_eq!(
+            oracle.grow(MAX_OBSERVATIONS + 1).unwrap_err(),
+            ErrorCode::InvalidOracleCardinality.into()
+        );
+        assert!(oracle.grow(MAX_OBSERVATIONS).is_ok());
+    }
+
+    #[test]
+    fn test_observe_current() {
+        let mut oracle = initialized_oracle(0);
+        oracle.write(10, 5, 1);
+        // Exactly at the newest observation
+        assert_eq!(oracle.observe(10, 0, 7, 1).unwrap().0, 50);
+        // After the newest observation, extrapolated with the current tick
+        assert_eq!(oracle.observe(15, 0, 7, 1).unwrap().0, 85);
+    }
+
+    #[test]
+    fn test_observe_interpolates() {
+        let mut oracle = initialized_oracle(0);
+        oracle.grow(10).unwrap();
+        oracle.write(10, 2, 1); // tick 2 from 0..10
+        oracle.write(20, 4, 1); // tick 4 from 10..20
+        oracle.write(30, 6, 1); // tick 6 from 20..30
+
+        assert_eq!(oracle.observe(30, 20, 0, 1).unwrap().0, 20);
+        assert_eq!(oracle.observe(30, 15, 0, 1).unwrap().0, 40);
+        assert_eq!(oracle.observe(30, 5, 0, 1).unwrap().0, 90);
+        assert_eq!(oracle.observe(30, 30, 0, 1).unwrap().0, 0);
+
+        // TWAP tick over the last 20 seconds
+        let (now, _) = oracle.observe(30, 0, 0, 1).unwrap();
+        let (then, _) = oracle.observe(30, 20, 0, 1).unwrap();
+        assert_eq!((now - then) / 20, 5);
+    }
+
+    #[test]
+    fn test_observe_interpolates_without_truncating_rate() {
+        let mut oracle = initialized_oracle(0);
+        oracle.grow(10).unwrap();
+        oracle.write(3, 1, 9); // tick 1 and liquidity 9 from 0..3
+        oracle.write(6, 0, 1);
+
+        let seconds_per_liquidity_x64 = (3u128 << Q64_RESOLUTION) / 9;
+        let (tick_cumulative, seconds_per_liquidity_cumulative_x64) =
+            oracle.observe(6, 4, 0, 1).unwrap();
+        assert_eq!(tick_cumulative, 2);
+        assert_eq!(
+            seconds_per_liquidity_cumulative_x64,
+            seconds_per_liquidity_x64 * 2 / 3
+        );
+        // Dividing by the observation delta first would truncate the rate
+        assert_ne!(
+            seconds_per_liquidity_cumulative_x64,
+            seconds_per_liquidity_x64 / 3 * 2
+        );
+    }
+
+    #[test]
+    fn test_observe_after_wrap_around() {
+        let mut oracle = initialized_oracle(0);
+        oracle.grow(3).unwrap();
+        for timestamp in 1..=5 {
+            oracle.write(timestamp * 10, 1, 1);
+        }
+        // Stored observations are at 30, 40 and 50
+        assert_eq!(oracle.observe(50, 20, 0, 1).unwrap().0, 30);
+        assert_eq!(oracle.observe(50, 15, 0, 1).unwrap().0, 35);
+        assert_eq!(
+            oracle.observe(50, 21, 0, 1).unwrap_err(),
+            ErrorCode::OracleObservationTooOld.into()
+        );
+    }
+
+    #[test]
+    fn test_observe_too_old() {
+        let oracle = initialized_oracle(100);
+        assert_eq!(
+            oracle.observe(100, 101, 0, 1).unwrap_err(),
+            ErrorCode::OracleObservationTooOld.into()
+        );
+        assert_eq!(
+            oracle.observe(150, 51, 0, 1).unwrap_err(),
+            ErrorCode::OracleObservationTooOld.into()
+        );
+    }
+}
@@ -256,6 +256,34 @@ impl TickArray {
         Ok(&self.ticks[offset as usize])
     }
 
+    /// Updates the Tick object at the given tick-index & tick-spacing
+    ///
+    /// # Parameters
+    /// - `tick_index` - the tick index the desired Tick object is stored in
+    /// - `tick_spacing` - A u8 integer of the tick spacing for this whirlpool
+    /// - `update` - A reference to a TickUpdate object to update the Tick object at the given index
+    ///
+    /// # Errors
+    /// - `TickNotFound`: - The provided tick-index is not an initializable tick index in this Whirlpool w/ this tick-spacing.
+    pub fn update_tick(
+        &mut self,
+        tick_index: i32,
+        tick_spacing: u16,
+        update: &TickUpdate,
+    ) -> Result<()> {
+        if !self.check_in_array_bounds(tick_index, tick_spacing)
+            || !Tick::check_is_usable_tick(tick_index, tick_spacing)
+        {
+            return Err(ErrorCode::TickNotFound.into());
+        }
+        let offset = self.tick_offset(tick_index, tick_spacing)?;
+        if offset < 0 {
+            return Err(ErrorCode::TickNotFound.into());
+        }
+        self.ticks[offset as usize].update(update);
+        Ok(())
+    }
+
     /// Checks that this array holds the next tick index for the current tick index, given the pool's tick spacing & search direction.
     ///
     /// unshifted checks on [start, start + TICK_ARRAY_SIZE * tick_spacing)
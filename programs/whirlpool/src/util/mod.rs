@@ -1,5 +1,9 @@
 pub mod swap_tick_sequence;
+pub mod swap_utils;
+pub mod token;
 pub mod util;
 
 pub use swap_tick_sequence::*;
+pub use swap_utils::*;
+pub use token::*;
 pub use util::*;
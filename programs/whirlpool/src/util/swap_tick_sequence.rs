@@ -1,16 +1,17 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use std::cell::RefMut;
 
-pub struct SwapTickSequence {
-    pub arrays: Vec<TickArray>,
+pub struct SwapTickSequence<'info> {
+    pub arrays: Vec<RefMut<'info, TickArray>>,
 }
 
-impl SwapTickSequence {
+impl<'info> SwapTickSequence<'info> {
     pub fn new(
-        ta0: TickArray,
-        ta1: Option<TickArray>,
-        ta2: Option<TickArray>,
+        ta0: RefMut<'info, TickArray>,
+        ta1: Option<RefMut<'info, TickArray>>,
+        ta2: Option<RefMut<'info, TickArray>>,
     ) -> Self {
         let mut vec = Vec::with_capacity(3);
         vec.push(ta0);
@@ -23,6 +24,18 @@ impl SwapTickSequence {
         Self { arrays: vec }
     }
 
+    /// Append tick arrays to the end of the sequence, in the order they will be traversed.
+    ///
+    /// # Errors
+    /// - `AccountNotMutable` - A provided tick-array is not writable
+    /// - `AccountBorrowFailed` - A provided tick-array is already part of the sequence
+    pub fn extend(&mut self, tick_arrays: &'info [AccountLoader<'_, TickArray>]) -> Result<()> {
+        for tick_array in tick_arrays {
+            self.arrays.push(tick_array.load_mut()?);
+        }
+        Ok(())
+    }
+
     /// Get the Tick object at the given tick-index & tick-spacing
     ///
     /// # Parameters
@@ -58,22 +71,22 @@ impl SwapTickSequence {
     /// # Errors
     /// - `TickArrayIndexOutofBounds` - The provided array-index is out of bounds
     /// - `TickNotFound`: - The provided tick-index is not an initializable tick index in this Whirlpool w/ this tick-spacing.
-    // pub fn update_tick(
-    //     &mut self,
-    //     array_index: usize,
-    //     tick_index: i32,
-    //     tick_spacing: u16,
-    //     update: &TickUpdate,
-    // ) -> Result<()> {
-    //     let array = self.arrays.get_mut(array_index);
-    //     match array {
-    //         Some(array) => {
-    //             array.update_tick(tick_index, tick_spacing, update)?;
-    //             Ok(())
-    //         }
-    //         _ => Err(ErrorCode::TickArrayIndexOutofBounds.into()),
-    //     }
-    // }
+    pub fn update_tick(
+        &mut self,
+        array_index: usize,
+        tick_index: i32,
+        tick_spacing: u16,
+        update: &TickUpdate,
+    ) -> Result<()> {
+        let array = self.arrays.get_mut(array_index);
+        match array {
+            Some(array) => {
+                array.update_tick(tick_index, tick_spacing, update)?;
+                Ok(())
+            }
+            _ => Err(ErrorCode::TickArrayIndexOutofBounds.into()),
+        }
+    }
 
     pub fn get_tick_offset(
         &self,
@@ -160,3 +173,28 @@ impl SwapTickSequence {
         }
     }
 }
+
+/// Load the additional tick arrays passed through remaining accounts and assign each of them to
+/// the whirlpool it belongs to. The relative order of the tick arrays is preserved.
+///
+/// # Returns
+/// - `Vec<Vec<AccountLoader>>`: The tick arrays of each whirlpool, in the order of `whirlpools`
+/// - `DifferentWhirlpoolTickArrayAccount` - A tick-array does not belong to any of the whirlpools
+pub fn load_remaining_tick_arrays<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    whirlpools: &[Pubkey],
+) -> Result<Vec<Vec<AccountLoader<'info, TickArray>>>> {
+    let mut tick_arrays: Vec<Vec<AccountLoader<'info, TickArray>>> =
+        whirlpools.iter().map(|_| Vec::new()).collect();
+
+    for account_info in remaining_accounts {
+        let tick_array = AccountLoader::<TickArray>::try_from(account_info)?;
+        let tick_array_whirlpool = tick_array.load()?.whirlpool;
+        match whirlpools.iter().position(|w| *w == tick_array_whirlpool) {
+            Some(index) => tick_arrays[index].push(tick_array),
+            None => return Err(ErrorCode::DifferentWhirlpoolTickArrayAccount.into()),
+        }
+    }
+
+    Ok(tick_arrays)
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{
+    manager::swap_manager::PostSwapUpdate,
+    state::{Oracle, Whirlpool},
+};
+
+use super::{transfer_from_owner_to_vault, transfer_from_vault_to_owner};
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_and_swap_whirlpool<'info>(
+    whirlpool: &mut Account<'info, Whirlpool>,
+    token_authority: &Signer<'info>,
+    token_owner_account_a: &Account<'info, TokenAccount>,
+    token_owner_account_b: &Account<'info, TokenAccount>,
+    token_vault_a: &Account<'info, TokenAccount>,
+    token_vault_b: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    swap_update: PostSwapUpdate,
+    is_token_fee_in_a: bool,
+    reward_last_updated_timestamp: u64,
+) -> Result<()> {
+    whirlpool.update_after_swap(
+        swap_update.next_liquidity,
+        swap_update.next_tick_index,
+        swap_update.next_sqrt_price,
+        swap_update.next_fee_growth_global,
+        swap_update.next_reward_infos,
+        swap_update.next_protocol_fee,
+        is_token_fee_in_a,
+        reward_last_updated_timestamp,
+    );
+
+    perform_swap(
+        whirlpool,
+        token_authority,
+        token_owner_account_a,
+        token_owner_account_b,
+        token_vault_a,
+        token_vault_b,
+        token_program,
+        swap_update.amount_a,
+        swap_update.amount_b,
+        is_token_fee_in_a,
+    )
+}
+
+// Records an observation of the whirlpool state in effect before this swap or liquidity change.
+// The oracle is opt-in: observations are skipped unless the caller passes the oracle as a
+// writable account and the oracle has been initialized.
+pub fn update_oracle(
+    oracle: &AccountInfo,
+    whirlpool: &Account<Whirlpool>,
+    timestamp: u64,
+) -> Result<()> {
+    if !oracle.is_writable || oracle.owner != &Oracle::owner() || oracle.data_len() != Oracle::LEN {
+        return Ok(());
+    }
+
+    let mut data = oracle.try_borrow_mut_data()?;
+    if data[..8] != Oracle::DISCRIMINATOR {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    let oracle: &mut Oracle = bytemuck::from_bytes_mut(&mut data[8..]);
+    if oracle.whirlpool != whirlpool.key() {
+        return Err(ErrorCode::ConstraintSeeds.into());
+    }
+    oracle.write(timestamp, whirlpool.tick_current_index, whirlpool.liquidity);
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn perform_swap<'info>(
+    whirlpool: &Account<'info, Whirlpool>,
+    token_authority: &Signer<'info>,
+    token_owner_account_a: &Account<'info, TokenAccount>,
+    token_owner_account_b: &Account<'info, TokenAccount>,
+    token_vault_a: &Account<'info, TokenAccount>,
+    token_vault_b: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount_a: u64,
+    amount_b: u64,
+    a_to_b: bool,
+) -> Result<()> {
+    // Transfer from user to pool
+    let (deposit_account_user, deposit_account_pool, deposit_amount) = if a_to_b {
+        (token_owner_account_a, token_vault_a, amount_a)
+    } else {
+        (token_owner_account_b, token_vault_b, amount_b)
+    };
+
+    // Transfer from pool to user
+    let (withdrawal_account_user, withdrawal_account_pool, withdrawal_amount) = if a_to_b {
+        (token_owner_account_b, token_vault_b, amount_b)
+    } else {
+        (token_owner_account_a, token_vault_a, amount_a)
+    };
+
+    transfer_from_owner_to_vault(
+        token_authority,
+        deposit_account_user,
+        deposit_account_pool,
+        token_program,
+        deposit_amount,
+    )?;
+
+    transfer_from_vault_to_owner(
+        whirlpool,
+        withdrawal_account_pool,
+        withdrawal_account_user,
+        token_program,
+        withdrawal_amount,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use std::convert::TryInto;
+
+use crate::ParseError;
+
+/// The accounts owned by the Whirlpool program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountType {
+    Whirlpool,
+    TickArray,
+    Position,
+    PositionBundle,
+    FeeTier,
+    WhirlpoolsConfig,
+    Oracle,
+}
+
+impl AccountType {
+    pub const ALL: [AccountType; 7] = [
+        AccountType::Whirlpool,
+        AccountType::TickArray,
+        AccountType::Position,
+        AccountType::PositionBundle,
+        AccountType::FeeTier,
+        AccountType::WhirlpoolsConfig,
+        AccountType::Oracle,
+    ];
+
+    /// The Anchor discriminator of the account: the first 8 bytes of
+    /// sha256("account:<AccountName>").
+    pub fn discriminator(&self) -> [u8; 8] {
+        match self {
+            AccountType::Whirlpool => [63, 149, 209, 12, 225, 128, 99, 9],
+            AccountType::TickArray => [69, 97, 189, 190, 110, 7, 66, 187],
+            AccountType::Position => [170, 188, 143, 228, 122, 64, 247, 208],
+            AccountType::PositionBundle => [129, 169, 175, 65, 185, 95, 32, 100],
+            AccountType::FeeTier => [56, 75, 159, 76, 142, 68, 190, 105],
+            AccountType::WhirlpoolsConfig => [157, 20, 49, 224, 217, 87, 193, 254],
+            AccountType::Oracle => [139, 194, 131, 179, 140, 179, 229, 244],
+        }
+    }
+
+    /// The size of the account data in bytes, including the discriminator.
+    pub fn size(&self) -> usize {
+        match self {
+            AccountType::Whirlpool => 8 + 261 + 384,
+            AccountType::TickArray => 8 + 36 + 113 * 88,
+            AccountType::Position => 8 + 136 + 72,
+            AccountType::PositionBundle => 8 + 32 + 32 + 64,
+            AccountType::FeeTier => 8 + 32 + 4,
+            AccountType::WhirlpoolsConfig => 8 + 96 + 4,
+            AccountType::Oracle => 8 + 38 + 33 * 300,
+        }
+    }
+
+    pub fn from_discriminator(discriminator: &[u8; 8]) -> Option<AccountType> {
+        AccountType::ALL
+            .iter()
+            .copied()
+            .find(|account_type| account_type.discriminator() == *discriminator)
+    }
+
+    /// Detect the account type of raw account data from its discriminator.
+    pub fn detect(data: &[u8]) -> Result<AccountType, ParseError> {
+        let discriminator: [u8; 8] = data
+            .get(..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ParseError::MissingDiscriminator)?;
+        AccountType::from_discriminator(&discriminator)
+            .ok_or(ParseError::UnknownDiscriminator(discriminator))
+    }
+
+    // Check that the data holds an account of this type, returning the data after the
+    // discriminator
+    pub(crate) fn check<'a>(&self, data: &'a [u8]) -> Result<&'a [u8], ParseError> {
+        let actual = AccountType::detect(data)?;
+        if actual != *self {
+            return Err(ParseError::AccountTypeMismatch {
+                expected: *self,
+                actual,
+            });
+        }
+        if data.len() != self.size() {
+            return Err(ParseError::InvalidAccountSize {
+                account_type: *self,
+                expected: self.size(),
+                actual: data.len(),
+            });
+        }
+        Ok(&data[8..])
+    }
+}
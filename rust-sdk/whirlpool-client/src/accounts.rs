@@ -0,0 +1,624 @@
+use solana_program::pubkey::Pubkey;
+
+use crate::{reader::Reader, AccountType, ParseError};
+
+pub const NUM_REWARDS: usize = 3;
+pub const TICK_ARRAY_SIZE: usize = 88;
+pub const POSITION_BITMAP_SIZE: usize = 32;
+pub const MAX_OBSERVATIONS: usize = 300;
+
+/// An account of the Whirlpool program that can be parsed from raw account data.
+pub trait ParsableAccount: Sized {
+    const ACCOUNT_TYPE: AccountType;
+
+    /// Parse the account from its data, including the discriminator.
+    ///
+    /// # Returns
+    /// - `MissingDiscriminator` / `UnknownDiscriminator`: The data is not a program account
+    /// - `AccountTypeMismatch`: The data holds a different account type
+    /// - `InvalidAccountSize`: The data length does not match the account size
+    fn parse(data: &[u8]) -> Result<Self, ParseError>;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WhirlpoolRewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub emissions_per_second_x64: u128,
+    pub growth_global_x64: u128,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Whirlpool {
+    pub whirlpools_config: Pubkey,
+    pub whirlpool_bump: [u8; 1],
+    pub tick_spacing: u16,
+    pub tick_spacing_seed: [u8; 2],
+    pub fee_rate: u16,
+    pub protocol_fee_rate: u16,
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+    pub protocol_fee_owed_a: u64,
+    pub protocol_fee_owed_b: u64,
+    pub token_mint_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub fee_growth_global_a: u128,
+    pub token_mint_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub fee_growth_global_b: u128,
+    pub reward_last_updated_timestamp: u64,
+    pub reward_infos: [WhirlpoolRewardInfo; NUM_REWARDS],
+}
+
+impl ParsableAccount for Whirlpool {
+    const ACCOUNT_TYPE: AccountType = AccountType::Whirlpool;
+
+    fn parse(data: &[u8]) -> Result<Self, ParseError> {
+        let mut reader = Reader::new(Self::ACCOUNT_TYPE.check(data)?);
+        Ok(Whirlpool {
+            whirlpools_config: reader.read_pubkey(),
+            whirlpool_bump: reader.read_bytes(),
+            tick_spacing: reader.read_u16(),
+            tick_spacing_seed: reader.read_bytes(),
+            fee_rate: reader.read_u16(),
+            protocol_fee_rate: reader.read_u16(),
+            liquidity: reader.read_u128(),
+            sqrt_price: reader.read_u128(),
+            tick_current_index: reader.read_i32(),
+            protocol_fee_owed_a: reader.read_u64(),
+            protocol_fee_owed_b: reader.read_u64(),
+            token_mint_a: reader.read_pubkey(),
+            token_vault_a: reader.read_pubkey(),
+            fee_growth_global_a: reader.read_u128(),
+            token_mint_b: reader.read_pubkey(),
+            token_vault_b: reader.read_pubkey(),
+            fee_growth_global_b: reader.read_u128(),
+            reward_last_updated_timestamp: reader.read_u64(),
+            reward_infos: std::array::from_fn(|_| WhirlpoolRewardInfo {
+                mint: reader.read_pubkey(),
+                vault: reader.read_pubkey(),
+                authority: reader.read_pubkey(),
+                emissions_per_second_x64: reader.read_u128(),
+                growth_global_x64: reader.read_u128(),
+            }),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tick {
+    pub initialized: bool,
+    pub liquidity_net: i128,
+    pub liquidity_gross: u128,
+    pub fee_growth_outside_a: u128,
+    pub fee_growth_outside_b: u128,
+    pub reward_growths_outside: [u128; NUM_REWARDS],
+}
+
+// TickArray is a packed zero-copy account on-chain; its fields are laid out back to back
+// without padding, which is the order they are read in here.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TickArray {
+    pub start_tick_index: i32,
+    pub ticks: [Tick; TICK_ARRAY_SIZE],
+    pub whirlpool: Pubkey,
+}
+
+impl ParsableAccount for TickArray {
+    const ACCOUNT_TYPE: AccountType = AccountType::TickArray;
+
+    fn parse(data: &[u8]) -> Result<Self, ParseError> {
+        let mut reader = Reader::new(Self::ACCOUNT_TYPE.check(data)?);
+        Ok(TickArray {
+            start_tick_index: reader.read_i32(),
+            ticks: std::array::from_fn(|_| Tick {
+                initialized: reader.read_bool(),
+                liquidity_net: reader.read_i128(),
+                liquidity_gross: reader.read_u128(),
+                fee_growth_outside_a: reader.read_u128(),
+                fee_growth_outside_b: reader.read_u128(),
+                reward_growths_outside: std::array::from_fn(|_| reader.read_u128()),
+            }),
+            whirlpool: reader.read_pubkey(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PositionRewardInfo {
+    pub growth_inside_checkpoint: u128,
+    pub amount_owed: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Position {
+    pub whirlpool: Pubkey,
+    pub position_mint: Pubkey,
+    pub liquidity: u128,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub fee_growth_checkpoint_a: u128,
+    pub fee_owed_a: u64,
+    pub fee_growth_checkpoint_b: u128,
+    pub fee_owed_b: u64,
+    pub reward_infos: [PositionRewardInfo; NUM_REWARDS],
+}
+
+impl ParsableAccount for Position {
+    const ACCOUNT_TYPE: AccountType = AccountType::Position;
+
+    fn parse(data: &[u8]) -> Result<Self, ParseError> {
+        let mut reader = Reader::new(Self::ACCOUNT_TYPE.check(data)?);
+        Ok(Position {
+            whirlpool: reader.read_pubkey(),
+            position_mint: reader.read_pubkey(),
+            liquidity: reader.read_u128(),
+            tick_lower_index: reader.read_i32(),
+            tick_upper_index: reader.read_i32(),
+            fee_growth_checkpoint_a: reader.read_u128(),
+            fee_owed_a: reader.read_u64(),
+            fee_growth_checkpoint_b: reader.read_u128(),
+            fee_owed_b: reader.read_u64(),
+            reward_infos: std::array::from_fn(|_| PositionRewardInfo {
+                growth_inside_checkpoint: reader.read_u128(),
+                amount_owed: reader.read_u64(),
+            }),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PositionBundle {
+    pub position_bundle_mint: Pubkey,
+    pub position_bitmap: [u8; POSITION_BITMAP_SIZE],
+}
+
+impl PositionBundle {
+    /// Whether the bundled position at `bundle_index` is open.
+    pub fn is_bundle_index_in_use(&self, bundle_index: u16) -> bool {
+        let index = bundle_index as usize;
+        match self.position_bitmap.get(index / 8) {
+            Some(byte) => byte & (1 << (index % 8)) != 0,
+            None => false,
+        }
+    }
+}
+
+impl ParsableAccount for PositionBundle {
+    const ACCOUNT_TYPE: AccountType = AccountType::PositionBundle;
+
+    fn parse(data: &[u8]) -> Result<Self, ParseError> {
+        let mut reader = Reader::new(Self::ACCOUNT_TYPE.check(data)?);
+        Ok(PositionBundle {
+            position_bundle_mint: reader.read_pubkey(),
+            position_bitmap: reader.read_bytes(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeeTier {
+    pub whirlpools_config: Pubkey,
+    pub tick_spacing: u16,
+    pub default_fee_rate: u16,
+}
+
+impl ParsableAccount for FeeTier {
+    const ACCOUNT_TYPE: AccountType = AccountType::FeeTier;
+
+    fn parse(data: &[u8]) -> Result<Self, ParseError> {
+        let mut reader = Reader::new(Self::ACCOUNT_TYPE.check(data)?);
+        Ok(FeeTier {
+            whirlpools_config: reader.read_pubkey(),
+            tick_spacing: reader.read_u16(),
+            default_fee_rate: reader.read_u16(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WhirlpoolsConfig {
+    pub fee_authority: Pubkey,
+    pub collect_protocol_fees_authority: Pubkey,
+    pub reward_emissions_super_authority: Pubkey,
+    pub default_protocol_fee_rate: u16,
+}
+
+impl ParsableAccount for WhirlpoolsConfig {
+    const ACCOUNT_TYPE: AccountType = AccountType::WhirlpoolsConfig;
+
+    fn parse(data: &[u8]) -> Result<Self, ParseError> {
+        let mut reader = Reader::new(Self::ACCOUNT_TYPE.check(data)?);
+        Ok(WhirlpoolsConfig {
+            fee_authority: reader.read_pubkey(),
+            collect_protocol_fees_authority: reader.read_pubkey(),
+            reward_emissions_super_authority: reader.read_pubkey(),
+            default_protocol_fee_rate: reader.read_u16(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Observation {
+    pub timestamp: u64,
+    pub tick_cumulative: i64,
+    pub seconds_per_liquidity_cumulative_x64: u128,
+    pub initialized: bool,
+}
+
+// Oracle is a packed zero-copy account on-chain, read in declaration order like TickArray.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Oracle {
+    pub whirlpool: Pubkey,
+    pub observation_index: u16,
+    pub observation_cardinality: u16,
+    pub observation_cardinality_next: u16,
+    pub observations: [Observation; MAX_OBSERVATIONS],
+}
+
+impl ParsableAccount for Oracle {
+    const ACCOUNT_TYPE: AccountType = AccountType::Oracle;
+
+    fn parse(data: &[u8]) -> Result<Self, ParseError> {
+        let mut reader = Reader::new(Self::ACCOUNT_TYPE.check(data)?);
+        Ok(Oracle {
+            whirlpool: reader.read_pubkey(),
+            observation_index: reader.read_u16(),
+            observation_cardinality: reader.read_u16(),
+            observation_cardinality_next: reader.read_u16(),
+            observations: std::array::from_fn(|_| Observation {
+                timestamp: reader.read_u64(),
+                tick_cumulative: reader.read_i64(),
+                seconds_per_liquidity_cumulative_x64: reader.read_u128(),
+                initialized: reader.read_bool(),
+            }),
+        })
+    }
+}
+
+/// Any account of the Whirlpool program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WhirlpoolAccount {
+    Whirlpool(Box<Whirlpool>),
+    TickArray(Box<TickArray>),
+    Position(Position),
+    PositionBundle(PositionBundle),
+    FeeTier(FeeTier),
+    WhirlpoolsConfig(WhirlpoolsConfig),
+    Oracle(Box<Oracle>),
+}
+
+/// Parse raw account data into whichever account type its discriminator identifies.
+pub fn parse_account(data: &[u8]) -> Result<WhirlpoolAccount, ParseError> {
+    Ok(match AccountType::detect(data)? {
+        AccountType::Whirlpool => WhirlpoolAccount::Whirlpool(Box::new(Whirlpool::parse(data)?)),
+        AccountType::TickArray => WhirlpoolAccount::TickArray(Box::new(TickArray::parse(data)?)),
+        AccountType::Position => WhirlpoolAccount::Position(Position::parse(data)?),
+        AccountType::PositionBundle => {
+            WhirlpoolAccount::PositionBundle(PositionBundle::parse(data)?)
+        }
+        AccountType::FeeTier => WhirlpoolAccount::FeeTier(FeeTier::parse(data)?),
+        AccountType::WhirlpoolsConfig => {
+            WhirlpoolAccount::WhirlpoolsConfig(WhirlpoolsConfig::parse(data)?)
+        }
+        AccountType::Oracle => WhirlpoolAccount::Oracle(Box::new(Oracle::parse(data)?)),
+    })
+}
+
+#[cfg(test)]
+mod accounts_tests {
+    use super::*;
+    use anchor_lang::{AccountSerialize, Discriminator};
+    use whirlpool::state as program;
+
+    fn key(seed: u8) -> Pubkey {
+        Pubkey::new_from_array([seed; 32])
+    }
+
+    // Serialize a program account the way it is stored on-chain, padded to the account size
+    fn account_data<T: AccountSerialize>(account: &T, size: usize) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.resize(size, 0);
+        data
+    }
+
+    #[test]
+    fn test_account_types_match_program() {
+        let program_accounts = [
+            (program::Whirlpool::DISCRIMINATOR, program::Whirlpool::LEN),
+            (program::TickArray::DISCRIMINATOR, program::TickArray::LEN),
+            (program::Position::DISCRIMINATOR, program::Position::LEN),
+            (
+                program::PositionBundle::DISCRIMINATOR,
+                program::PositionBundle::LEN,
+            ),
+            (program::FeeTier::DISCRIMINATOR, program::FeeTier::LEN),
+            (
+                program::WhirlpoolsConfig::DISCRIMINATOR,
+                program::WhirlpoolsConfig::LEN,
+            ),
+            (program::Oracle::DISCRIMINATOR, program::Oracle::LEN),
+        ];
+        assert_eq!(AccountType::ALL.len(), program_accounts.len());
+        for (account_type, (discriminator, size)) in
+            AccountType::ALL.iter().zip(program_accounts.iter())
+        {
+            assert_eq!(account_type.discriminator(), *discriminator);
+            assert_eq!(account_type.size(), *size);
+        }
+    }
+
+    #[test]
+    fn test_parse_whirlpool() {
+        let mut whirlpool = program::Whirlpool {
+            whirlpools_config: key(1),
+            whirlpool_bump: [254],
+            tick_spacing: 64,
+            tick_spacing_seed: 64u16.to_le_bytes(),
+            fee_rate: 3000,
+            protocol_fee_rate: 300,
+            liquidity: 1 << 100,
+            sqrt_price: 1 << 64,
+            tick_current_index: -12345,
+            protocol_fee_owed_a: 7,
+            protocol_fee_owed_b: 8,
+            token_mint_a: key(2),
+            token_vault_a: key(3),
+            fee_growth_global_a: u128::MAX,
+            token_mint_b: key(4),
+            token_vault_b: key(5),
+            fee_growth_global_b: 9,
+            reward_last_updated_timestamp: 1_700_000_000,
+            ..Default::default()
+        };
+        whirlpool.reward_infos[2].mint = key(6);
+        whirlpool.reward_infos[2].emissions_per_second_x64 = 10;
+        whirlpool.reward_infos[2].growth_global_x64 = 11;
+
+        let data = account_data(&whirlpool, program::Whirlpool::LEN);
+        let parsed = Whirlpool::parse(&data).unwrap();
+
+        assert_eq!(parsed.whirlpools_config, key(1));
+        assert_eq!(parsed.whirlpool_bump, [254]);
+        assert_eq!(parsed.tick_spacing, 64);
+        assert_eq!(parsed.tick_spacing_seed, 64u16.to_le_bytes());
+        assert_eq!(parsed.fee_rate, 3000);
+        assert_eq!(parsed.protocol_fee_rate, 300);
+        assert_eq!(parsed.liquidity, 1 << 100);
+        assert_eq!(parsed.sqrt_price, 1 << 64);
+        assert_eq!(parsed.tick_current_index, -12345);
+        assert_eq!(parsed.protocol_fee_owed_a, 7);
+        assert_eq!(parsed.protocol_fee_owed_b, 8);
+        assert_eq!(parsed.token_mint_a, key(2));
+        assert_eq!(parsed.token_vault_a, key(3));
+        assert_eq!(parsed.fee_growth_global_a, u128::MAX);
+        assert_eq!(parsed.token_mint_b, key(4));
+        assert_eq!(parsed.token_vault_b, key(5));
+        assert_eq!(parsed.fee_growth_global_b, 9);
+        assert_eq!(parsed.reward_last_updated_timestamp, 1_700_000_000);
+        assert_eq!(
+            parsed.reward_infos[2],
+            WhirlpoolRewardInfo {
+                mint: key(6),
+                emissions_per_second_x64: 10,
+                growth_global_x64: 11,
+                ..Default::default()
+            }
+        );
+        assert_eq!(parsed.reward_infos[0], WhirlpoolRewardInfo::default());
+    }
+
+    #[test]
+    fn test_parse_tick_array() {
+        // The on-chain layout of the packed account: fields in declaration order, no padding
+        let mut data = program::TickArray::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&(-5632i32).to_le_bytes());
+        data.resize(data.len() + 87 * 113, 0);
+        data.push(1);
+        data.extend_from_slice(&(-42i128).to_le_bytes());
+        for value in [42u128, 1, 2, 3, 4, 5].iter() {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(key(1).as_ref());
+
+        let parsed = TickArray::parse(&data).unwrap();
+
+        assert_eq!(parsed.start_tick_index, -5632);
+        assert_eq!(parsed.whirlpool, key(1));
+        assert_eq!(
+            parsed.ticks[87],
+            Tick {
+                initialized: true,
+                liquidity_net: -42,
+                liquidity_gross: 42,
+                fee_growth_outside_a: 1,
+                fee_growth_outside_b: 2,
+                reward_growths_outside: [3, 4, 5],
+            }
+        );
+        assert_eq!(parsed.ticks[0], Tick::default());
+    }
+
+    #[test]
+    fn test_parse_position() {
+        let mut position = program::Position {
+            whirlpool: key(1),
+            position_mint: key(2),
+            liquidity: 100,
+            tick_lower_index: -128,
+            tick_upper_index: 128,
+            fee_growth_checkpoint_a: 3,
+            fee_owed_a: 4,
+            fee_growth_checkpoint_b: 5,
+            fee_owed_b: 6,
+            ..Default::default()
+        };
+        position.reward_infos[1].growth_inside_checkpoint = 7;
+        position.reward_infos[1].amount_owed = 8;
+
+        let data = account_data(&position, program::Position::LEN);
+        assert_eq!(
+            Position::parse(&data).unwrap(),
+            Position {
+                whirlpool: key(1),
+                position_mint: key(2),
+                liquidity: 100,
+                tick_lower_index: -128,
+                tick_upper_index: 128,
+                fee_growth_checkpoint_a: 3,
+                fee_owed_a: 4,
+                fee_growth_checkpoint_b: 5,
+                fee_owed_b: 6,
+                reward_infos: [
+                    PositionRewardInfo::default(),
+                    PositionRewardInfo {
+                        growth_inside_checkpoint: 7,
+                        amount_owed: 8,
+                    },
+                    PositionRewardInfo::default(),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_position_bundle() {
+        let mut position_bundle = program::PositionBundle {
+            position_bundle_mint: key(1),
+            ..Default::default()
+        };
+        position_bundle.position_bitmap[1] = 0b0000_0100;
+
+        let data = account_data(&position_bundle, program::PositionBundle::LEN);
+        let parsed = PositionBundle::parse(&data).unwrap();
+        assert_eq!(parsed.position_bundle_mint, key(1));
+        assert!(parsed.is_bundle_index_in_use(10));
+        assert!(!parsed.is_bundle_index_in_use(9));
+        assert!(!parsed.is_bundle_index_in_use(256));
+    }
+
+    #[test]
+    fn test_parse_fee_tier_and_config() {
+        let fee_tier = program::FeeTier {
+            whirlpools_config: key(1),
+            tick_spacing: 128,
+            default_fee_rate: 10000,
+        };
+        let data = account_data(&fee_tier, program::FeeTier::LEN);
+        assert_eq!(
+            FeeTier::parse(&data).unwrap(),
+            FeeTier {
+                whirlpools_config: key(1),
+                tick_spacing: 128,
+                default_fee_rate: 10000,
+            }
+        );
+
+        let config = program::WhirlpoolsConfig {
+            fee_authority: key(1),
+            collect_protocol_fees_authority: key(2),
+            reward_emissions_super_authority: key(3),
+            default_protocol_fee_rate: 300,
+        };
+        let data = account_data(&config, program::WhirlpoolsConfig::LEN);
+        assert_eq!(
+            parse_account(&data).unwrap(),
+            WhirlpoolAccount::WhirlpoolsConfig(WhirlpoolsConfig {
+                fee_authority: key(1),
+                collect_protocol_fees_authority: key(2),
+                reward_emissions_super_authority: key(3),
+                default_protocol_fee_rate: 300,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_oracle() {
+        // The on-chain layout of the packed account: fields in declaration order, no padding
+        let mut data = program::Oracle::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(key(1).as_ref());
+        for value in [1u16, 10, 20].iter() {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.resize(data.len() + program::Observation::LEN, 0);
+        data.extend_from_slice(&1_700_000_000u64.to_le_bytes());
+        data.extend_from_slice(&(-42i64).to_le_bytes());
+        data.extend_from_slice(&(3u128 << 64).to_le_bytes());
+        data.push(1);
+        data.resize(program::Oracle::LEN, 0);
+
+        let parsed = match parse_account(&data).unwrap() {
+            WhirlpoolAccount::Oracle(oracle) => oracle,
+            account => panic!("expected an oracle, found {:?}", account),
+        };
+
+        assert_eq!(parsed.whirlpool, key(1));
+        assert_eq!(parsed.observation_index, 1);
+        assert_eq!(parsed.observation_cardinality, 10);
+        assert_eq!(parsed.observation_cardinality_next, 20);
+        assert_eq!(parsed.observations[0], Observation::default());
+        assert_eq!(
+            parsed.observations[1],
+            Observation {
+                timestamp: 1_700_000_000,
+                tick_cumulative: -42,
+                seconds_per_liquidity_cumulative_x64: 3 << 64,
+                initialized: true,
+            }
+        );
+        assert_eq!(parsed.observations[299], Observation::default());
+    }
+
+    #[test]
+    fn test_parse_account_detects_type() {
+        let data = account_data(&program::Position::default(), program::Position::LEN);
+        assert_eq!(AccountType::detect(&data).unwrap(), AccountType::Position);
+        assert_eq!(
+            parse_account(&data).unwrap(),
+            WhirlpoolAccount::Position(Position::default())
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_account(&[1, 2, 3]).unwrap_err(),
+            ParseError::MissingDiscriminator
+        );
+        assert_eq!(
+            parse_account(&[0; 16]).unwrap_err(),
+            ParseError::UnknownDiscriminator([0; 8])
+        );
+
+        let data = account_data(&program::Position::default(), program::Position::LEN);
+        assert_eq!(
+            Whirlpool::parse(&data).unwrap_err(),
+            ParseError::AccountTypeMismatch {
+                expected: AccountType::Whirlpool,
+                actual: AccountType::Position,
+            }
+        );
+        assert_eq!(
+            parse_account(&data[..100]).unwrap_err(),
+            ParseError::InvalidAccountSize {
+                account_type: AccountType::Position,
+                expected: 216,
+                actual: 100,
+            }
+        );
+
+        let mut data = data;
+        data.push(0);
+        assert_eq!(
+            Position::parse(&data).unwrap_err(),
+            ParseError::InvalidAccountSize {
+                account_type: AccountType::Position,
+                expected: 216,
+                actual: 217,
+            }
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt;
+
+use crate::AccountType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    // The data is too short to hold an account discriminator
+    MissingDiscriminator,
+    // The discriminator does not belong to any Whirlpool program account
+    UnknownDiscriminator([u8; 8]),
+    // The discriminator belongs to a different account type than the one requested
+    AccountTypeMismatch {
+        expected: AccountType,
+        actual: AccountType,
+    },
+    // The data length does not match the size of the account type
+    InvalidAccountSize {
+        account_type: AccountType,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingDiscriminator => write!(f, "Account data has no discriminator"),
+            ParseError::UnknownDiscriminator(discriminator) => {
+                write!(f, "Unknown account discriminator {:?}", discriminator)
+            }
+            ParseError::AccountTypeMismatch { expected, actual } => {
+                write!(f, "Expected {:?} account, found {:?}", expected, actual)
+            }
+            ParseError::InvalidAccountSize {
+                account_type,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Invalid {:?} account size, expected {} bytes, found {}",
+                account_type, expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,14 @@
+//! Parsing of Whirlpool program accounts from raw account data.
+//!
+//! The crate does not depend on the Anchor runtime. Account types are detected from their
+//! Anchor discriminator, and the layouts, including the packed `TickArray` and `Oracle`, are
+//! read field by field so they can be used by any Rust client.
+
+pub mod account_type;
+pub mod accounts;
+pub mod error;
+mod reader;
+
+pub use account_type::*;
+pub use accounts::*;
+pub use error::*;
@@ -0,0 +1,54 @@
+use std::convert::TryInto;
+
+use solana_program::pubkey::Pubkey;
+
+// Reads little-endian fields in order. Callers check the data length against the account
+// size up front, so reads never run past the end of the data.
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    pub fn read_bytes<const N: usize>(&mut self) -> [u8; N] {
+        let bytes = self.data[self.offset..self.offset + N].try_into().unwrap();
+        self.offset += N;
+        bytes
+    }
+
+    pub fn read_bool(&mut self) -> bool {
+        self.read_bytes::<1>()[0] != 0
+    }
+
+    pub fn read_u16(&mut self) -> u16 {
+        u16::from_le_bytes(self.read_bytes())
+    }
+
+    pub fn read_i32(&mut self) -> i32 {
+        i32::from_le_bytes(self.read_bytes())
+    }
+
+    pub fn read_u64(&mut self) -> u64 {
+        u64::from_le_bytes(self.read_bytes())
+    }
+
+    pub fn read_i64(&mut self) -> i64 {
+        i64::from_le_bytes(self.read_bytes())
+    }
+
+    pub fn read_u128(&mut self) -> u128 {
+        u128::from_le_bytes(self.read_bytes())
+    }
+
+    pub fn read_i128(&mut self) -> i128 {
+        i128::from_le_bytes(self.read_bytes())
+    }
+
+    pub fn read_pubkey(&mut self) -> Pubkey {
+        Pubkey::new_from_array(self.read_bytes())
+    }
+}
@@ -0,0 +1,1394 @@
+//! Builders for the instructions of the Whirlpool program.
+//!
+//! Program derived addresses (whirlpools, positions, tick arrays, oracles, fee tiers and
+//! position bundles) are derived by the builders. Builders that only need the address of a
+//! whirlpool take it as a `Pubkey`, while builders that also read its vaults, config or reward
+//! infos take the `Whirlpool` state and derive the address from it. The swap builders take
+//! whirlpool addresses from the swap parameters.
+//! Position builders read the whirlpool address from the `Position` state and derive the
+//! position address from its position mint. The `bundled_position` builders derive it from the
+//! bundle index instead, as bundled positions store the position bundle mint as their mint.
+//! Oracles are passed as writable accounts, so swaps and liquidity changes record an oracle
+//! observation once the oracle of the pool has been initialized.
+//! Token accounts are passed in, apart from the position and position bundle token accounts
+//! that the program creates as associated token accounts.
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, sysvar},
+    system_program, InstructionData,
+};
+use anchor_spl::{associated_token, token};
+use whirlpool::{
+    constants::nft::whirlpool_nft_update_auth,
+    errors::ErrorCode,
+    instructions::RouteSwapHop,
+    state::{
+        OpenPositionBumps, OpenPositionWithMetadataBumps, Position, Whirlpool, WhirlpoolBumps,
+        TICK_ARRAY_SIZE,
+    },
+};
+
+use crate::{
+    find_bundled_position_address, find_fee_tier_address, find_oracle_address,
+    find_position_address, find_position_bundle_address, find_position_bundle_metadata_address,
+    find_position_metadata_address, find_tick_array_address, find_whirlpool_address,
+    get_swap_tick_array_addresses, SwapParams, TwoHopSwapParams,
+};
+
+fn build_instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: whirlpool::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn whirlpool_address(whirlpool: &Whirlpool) -> Pubkey {
+    find_whirlpool_address(
+        &whirlpool.whirlpools_config,
+        &whirlpool.token_mint_a,
+        &whirlpool.token_mint_b,
+        whirlpool.tick_spacing,
+    )
+    .0
+}
+
+// Pass the oracles as writable so the program records an observation of their pools
+fn record_oracle_observations(mut instruction: Instruction, oracles: &[Pubkey]) -> Instruction {
+    for account in instruction.accounts.iter_mut() {
+        if oracles.contains(&account.pubkey) {
+            account.is_writable = true;
+        }
+    }
+    instruction
+}
+
+// The vault of the reward at `reward_index`
+fn reward_vault(whirlpool: &Whirlpool, reward_index: u8) -> Result<Pubkey> {
+    match whirlpool.reward_infos.get(reward_index as usize) {
+        Some(reward_info) => Ok(reward_info.vault),
+        None => Err(ErrorCode::InvalidRewardIndex.into()),
+    }
+}
+
+// The address of the tick array containing `tick_index`
+fn tick_array_address(whirlpool: &Pubkey, tick_index: i32, tick_spacing: u16) -> Pubkey {
+    let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+    let start_tick_index = tick_index.div_euclid(ticks_in_array) * ticks_in_array;
+    find_tick_array_address(whirlpool, start_tick_index).0
+}
+
+pub fn initialize_config_ix(
+    config: &Pubkey,
+    funder: &Pubkey,
+    fee_authority: &Pubkey,
+    collect_protocol_fees_authority: &Pubkey,
+    reward_emissions_super_authority: &Pubkey,
+    default_protocol_fee_rate: u16,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::InitializeConfig {
+            config: *config,
+            funder: *funder,
+            system_program: system_program::ID,
+        },
+        whirlpool::instruction::InitializeConfig {
+            fee_authority: *fee_authority,
+            collect_protocol_fees_authority: *collect_protocol_fees_authority,
+            reward_emissions_super_authority: *reward_emissions_super_authority,
+            default_protocol_fee_rate,
+        },
+    )
+}
+
+pub fn initialize_fee_tier_ix(
+    config: &Pubkey,
+    funder: &Pubkey,
+    fee_authority: &Pubkey,
+    tick_spacing: u16,
+    default_fee_rate: u16,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::InitializeFeeTier {
+            config: *config,
+            fee_tier: find_fee_tier_address(config, tick_spacing).0,
+            funder: *funder,
+            fee_authority: *fee_authority,
+            system_program: system_program::ID,
+        },
+        whirlpool::instruction::InitializeFeeTier {
+            tick_spacing,
+            default_fee_rate,
+        },
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitializePoolParams {
+    pub whirlpools_config: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    // New token accounts, the vaults must sign the transaction
+    pub token_vault_a: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub funder: Pubkey,
+    pub tick_spacing: u16,
+    pub initial_sqrt_price: u128,
+}
+
+pub fn initialize_pool_ix(params: &InitializePoolParams) -> Instruction {
+    let (whirlpool, whirlpool_bump) = find_whirlpool_address(
+        &params.whirlpools_config,
+        &params.token_mint_a,
+        &params.token_mint_b,
+        params.tick_spacing,
+    );
+    build_instruction(
+        whirlpool::accounts::InitializePool {
+            whirlpools_config: params.whirlpools_config,
+            token_mint_a: params.token_mint_a,
+            token_mint_b: params.token_mint_b,
+            funder: params.funder,
+            whirlpool,
+            token_vault_a: params.token_vault_a,
+            token_vault_b: params.token_vault_b,
+            fee_tier: find_fee_tier_address(&params.whirlpools_config, params.tick_spacing).0,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        whirlpool::instruction::InitializePool {
+            bumps: WhirlpoolBumps { whirlpool_bump },
+            tick_spacing: params.tick_spacing,
+            initial_sqrt_price: params.initial_sqrt_price,
+        },
+    )
+}
+
+pub fn initialize_tick_array_ix(
+    whirlpool: &Pubkey,
+    funder: &Pubkey,
+    start_tick_index: i32,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::InitializeTickArray {
+            whirlpool: *whirlpool,
+            funder: *funder,
+            tick_array: find_tick_array_address(whirlpool, start_tick_index).0,
+            system_program: system_program::ID,
+        },
+        whirlpool::instruction::InitializeTickArray { start_tick_index },
+    )
+}
+
+pub fn initialize_oracle_ix(whirlpool: &Pubkey, funder: &Pubkey) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::InitializeOracle {
+            whirlpool: *whirlpool,
+            funder: *funder,
+            oracle: find_oracle_address(whirlpool).0,
+            system_program: system_program::ID,
+        },
+        whirlpool::instruction::InitializeOracle {},
+    )
+}
+
+pub fn increase_oracle_cardinality_ix(
+    whirlpool: &Pubkey,
+    observation_cardinality_next: u16,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::IncreaseOracleCardinality {
+            whirlpool: *whirlpool,
+            oracle: find_oracle_address(whirlpool).0,
+        },
+        whirlpool::instruction::IncreaseOracleCardinality {
+            observation_cardinality_next,
+        },
+    )
+}
+
+pub fn set_fee_authority_ix(
+    whirlpools_config: &Pubkey,
+    fee_authority: &Pubkey,
+    new_fee_authority: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::SetFeeAuthority {
+            whirlpools_config: *whirlpools_config,
+            fee_authority: *fee_authority,
+            new_fee_authority: *new_fee_authority,
+        },
+        whirlpool::instruction::SetFeeAuthority {},
+    )
+}
+
+pub fn set_collect_protocol_fees_authority_ix(
+    whirlpools_config: &Pubkey,
+    collect_protocol_fees_authority: &Pubkey,
+    new_collect_protocol_fees_authority: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::SetCollectProtocolFeesAuthority {
+            whirlpools_config: *whirlpools_config,
+            collect_protocol_fees_authority: *collect_protocol_fees_authority,
+            new_collect_protocol_fees_authority: *new_collect_protocol_fees_authority,
+        },
+        whirlpool::instruction::SetCollectProtocolFeesAuthority {},
+    )
+}
+
+pub fn set_reward_emissions_super_authority_ix(
+    whirlpools_config: &Pubkey,
+    reward_emissions_super_authority: &Pubkey,
+    new_reward_emissions_super_authority: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::SetRewardEmissionsSuperAuthority {
+            whirlpools_config: *whirlpools_config,
+            reward_emissions_super_authority: *reward_emissions_super_authority,
+            new_reward_emissions_super_authority: *new_reward_emissions_super_authority,
+        },
+        whirlpool::instruction::SetRewardEmissionsSuperAuthority {},
+    )
+}
+
+pub fn set_default_fee_rate_ix(
+    whirlpools_config: &Pubkey,
+    fee_authority: &Pubkey,
+    tick_spacing: u16,
+    default_fee_rate: u16,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::SetDefaultFeeRate {
+            whirlpools_config: *whirlpools_config,
+            fee_tier: find_fee_tier_address(whirlpools_config, tick_spacing).0,
+            fee_authority: *fee_authority,
+        },
+        whirlpool::instruction::SetDefaultFeeRate { default_fee_rate },
+    )
+}
+
+pub fn set_default_protocol_fee_rate_ix(
+    whirlpools_config: &Pubkey,
+    fee_authority: &Pubkey,
+    default_protocol_fee_rate: u16,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::SetDefaultProtocolFeeRate {
+            whirlpools_config: *whirlpools_config,
+            fee_authority: *fee_authority,
+        },
+        whirlpool::instruction::SetDefaultProtocolFeeRate {
+            default_protocol_fee_rate,
+        },
+    )
+}
+
+pub fn set_fee_rate_ix(
+    whirlpool: &Whirlpool,
+    fee_authority: &Pubkey,
+    fee_rate: u16,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::SetFeeRate {
+            whirlpools_config: whirlpool.whirlpools_config,
+            whirlpool: whirlpool_address(whirlpool),
+            fee_authority: *fee_authority,
+        },
+        whirlpool::instruction::SetFeeRate { fee_rate },
+    )
+}
+
+pub fn set_protocol_fee_rate_ix(
+    whirlpool: &Whirlpool,
+    fee_authority: &Pubkey,
+    protocol_fee_rate: u16,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::SetProtocolFeeRate {
+            whirlpools_config: whirlpool.whirlpools_config,
+            whirlpool: whirlpool_address(whirlpool),
+            fee_authority: *fee_authority,
+        },
+        whirlpool::instruction::SetProtocolFeeRate { protocol_fee_rate },
+    )
+}
+
+pub fn collect_protocol_fees_ix(
+    whirlpool: &Whirlpool,
+    collect_protocol_fees_authority: &Pubkey,
+    token_destination_a: &Pubkey,
+    token_destination_b: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::CollectProtocolFees {
+            whirlpools_config: whirlpool.whirlpools_config,
+            whirlpool: whirlpool_address(whirlpool),
+            collect_protocol_fees_authority: *collect_protocol_fees_authority,
+            token_vault_a: whirlpool.token_vault_a,
+            token_vault_b: whirlpool.token_vault_b,
+            token_destination_a: *token_destination_a,
+            token_destination_b: *token_destination_b,
+            token_program: token::ID,
+        },
+        whirlpool::instruction::CollectProtocolFees {},
+    )
+}
+
+pub fn initialize_reward_ix(
+    whirlpool: &Pubkey,
+    reward_authority: &Pubkey,
+    funder: &Pubkey,
+    reward_mint: &Pubkey,
+    reward_vault: &Pubkey,
+    reward_index: u8,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::InitializeReward {
+            reward_authority: *reward_authority,
+            funder: *funder,
+            whirlpool: *whirlpool,
+            reward_mint: *reward_mint,
+            reward_vault: *reward_vault,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        whirlpool::instruction::InitializeReward { reward_index },
+    )
+}
+
+/// The reward vault is read from the reward info at `reward_index`.
+///
+/// # Returns
+/// - `Instruction`: The set reward emissions instruction
+/// - `InvalidRewardIndex`: - `reward_index` is not less than NUM_REWARDS
+pub fn set_reward_emissions_ix(
+    whirlpool: &Whirlpool,
+    reward_authority: &Pubkey,
+    reward_index: u8,
+    emissions_per_second_x64: u128,
+) -> Result<Instruction> {
+    Ok(build_instruction(
+        whirlpool::accounts::SetRewardEmissions {
+            whirlpool: whirlpool_address(whirlpool),
+            reward_authority: *reward_authority,
+            reward_vault: reward_vault(whirlpool, reward_index)?,
+        },
+        whirlpool::instruction::SetRewardEmissions {
+            reward_index,
+            emissions_per_second_x64,
+        },
+    ))
+}
+
+pub fn set_reward_authority_ix(
+    whirlpool: &Pubkey,
+    reward_authority: &Pubkey,
+    new_reward_authority: &Pubkey,
+    reward_index: u8,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::SetRewardAuthority {
+            whirlpool: *whirlpool,
+            reward_authority: *reward_authority,
+            new_reward_authority: *new_reward_authority,
+        },
+        whirlpool::instruction::SetRewardAuthority { reward_index },
+    )
+}
+
+pub fn set_reward_authority_by_super_authority_ix(
+    whirlpool: &Whirlpool,
+    reward_emissions_super_authority: &Pubkey,
+    new_reward_authority: &Pubkey,
+    reward_index: u8,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::SetRewardAuthorityBySuperAuthority {
+            whirlpools_config: whirlpool.whirlpools_config,
+            whirlpool: whirlpool_address(whirlpool),
+            reward_emissions_super_authority: *reward_emissions_super_authority,
+            new_reward_authority: *new_reward_authority,
+        },
+        whirlpool::instruction::SetRewardAuthorityBySuperAuthority { reward_index },
+    )
+}
+
+/// The position token account is the associated token account of `owner`.
+pub fn open_position_ix(
+    whirlpool: &Pubkey,
+    funder: &Pubkey,
+    owner: &Pubkey,
+    position_mint: &Pubkey,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+) -> Instruction {
+    let (position, position_bump) = find_position_address(position_mint);
+    build_instruction(
+        whirlpool::accounts::OpenPosition {
+            funder: *funder,
+            owner: *owner,
+            position,
+            position_mint: *position_mint,
+            position_token_account: associated_token::get_associated_token_address(
+                owner,
+                position_mint,
+            ),
+            whirlpool: *whirlpool,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            associated_token_program: associated_token::ID,
+        },
+        whirlpool::instruction::OpenPosition {
+            bumps: OpenPositionBumps { position_bump },
+            tick_lower_index,
+            tick_upper_index,
+        },
+    )
+}
+
+/// The position token account is the associated token account of `owner`.
+pub fn open_position_with_metadata_ix(
+    whirlpool: &Pubkey,
+    funder: &Pubkey,
+    owner: &Pubkey,
+    position_mint: &Pubkey,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+) -> Instruction {
+    let (position, position_bump) = find_position_address(position_mint);
+    let (position_metadata_account, metadata_bump) = find_position_metadata_address(position_mint);
+    build_instruction(
+        whirlpool::accounts::OpenPositionWithMetadata {
+            funder: *funder,
+            owner: *owner,
+            position,
+            position_mint: *position_mint,
+            position_metadata_account,
+            position_token_account: associated_token::get_associated_token_address(
+                owner,
+                position_mint,
+            ),
+            whirlpool: *whirlpool,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            associated_token_program: associated_token::ID,
+            metadata_program: mpl_token_metadata::ID,
+            metadata_update_auth: whirlpool_nft_update_auth::ID,
+        },
+        whirlpool::instruction::OpenPositionWithMetadata {
+            bumps: OpenPositionWithMetadataBumps {
+                position_bump,
+                metadata_bump,
+            },
+            tick_lower_index,
+            tick_upper_index,
+        },
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncreaseLiquidityParams {
+    pub position_authority: Pubkey,
+    pub position_token_account: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub liquidity_amount: u128,
+    pub token_max_a: u64,
+    pub token_max_b: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecreaseLiquidityParams {
+    pub position_authority: Pubkey,
+    pub position_token_account: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub liquidity_amount: u128,
+    pub token_min_a: u64,
+    pub token_min_b: u64,
+}
+
+fn modify_liquidity_accounts(
+    whirlpool: &Whirlpool,
+    position_address: &Pubkey,
+    position: &Position,
+    position_authority: &Pubkey,
+    position_token_account: &Pubkey,
+    token_owner_account_a: &Pubkey,
+    token_owner_account_b: &Pubkey,
+) -> whirlpool::accounts::ModifyLiquidity {
+    whirlpool::accounts::ModifyLiquidity {
+        whirlpool: position.whirlpool,
+        token_program: token::ID,
+        position_authority: *position_authority,
+        position: *position_address,
+        position_token_account: *position_token_account,
+        token_owner_account_a: *token_owner_account_a,
+        token_owner_account_b: *token_owner_account_b,
+        token_vault_a: whirlpool.token_vault_a,
+        token_vault_b: whirlpool.token_vault_b,
+        tick_array_lower: tick_array_address(
+            &position.whirlpool,
+            position.tick_lower_index,
+            whirlpool.tick_spacing,
+        ),
+        tick_array_upper: tick_array_address(
+            &position.whirlpool,
+            position.tick_upper_index,
+            whirlpool.tick_spacing,
+        ),
+    }
+}
+
+fn increase_liquidity_at(
+    whirlpool: &Whirlpool,
+    position_address: &Pubkey,
+    position: &Position,
+    params: &IncreaseLiquidityParams,
+) -> Instruction {
+    let mut instruction = build_instruction(
+        modify_liquidity_accounts(
+            whirlpool,
+            position_address,
+            position,
+            &params.position_authority,
+            &params.position_token_account,
+            &params.token_owner_account_a,
+            &params.token_owner_account_b,
+        ),
+        whirlpool::instruction::IncreaseLiquidity {
+            liquidity_amount: params.liquidity_amount,
+            token_max_a: params.token_max_a,
+            token_max_b: params.token_max_b,
+        },
+    );
+    // The oracle is an optional remaining account
+    instruction.accounts.push(AccountMeta::new(
+        find_oracle_address(&position.whirlpool).0,
+        false,
+    ));
+    instruction
+}
+
+fn decrease_liquidity_at(
+    whirlpool: &Whirlpool,
+    position_address: &Pubkey,
+    position: &Position,
+    params: &DecreaseLiquidityParams,
+) -> Instruction {
+    let mut instruction = build_instruction(
+        modify_liquidity_accounts(
+            whirlpool,
+            position_address,
+            position,
+            &params.position_authority,
+            &params.position_token_account,
+            &params.token_owner_account_a,
+            &params.token_owner_account_b,
+        ),
+        whirlpool::instruction::DecreaseLiquidity {
+            liquidity_amount: params.liquidity_amount,
+            token_min_a: params.token_min_a,
+            token_min_b: params.token_min_b,
+        },
+    );
+    // The oracle is an optional remaining account
+    instruction.accounts.push(AccountMeta::new(
+        find_oracle_address(&position.whirlpool).0,
+        false,
+    ));
+    instruction
+}
+
+fn update_fees_and_rewards_at(
+    whirlpool: &Whirlpool,
+    position_address: &Pubkey,
+    position: &Position,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::UpdateFeesAndRewards {
+            whirlpool: position.whirlpool,
+            position: *position_address,
+            tick_array_lower: tick_array_address(
+                &position.whirlpool,
+                position.tick_lower_index,
+                whirlpool.tick_spacing,
+            ),
+            tick_array_upper: tick_array_address(
+                &position.whirlpool,
+                position.tick_upper_index,
+                whirlpool.tick_spacing,
+            ),
+        },
+        whirlpool::instruction::UpdateFeesAndRewards {},
+    )
+}
+
+fn collect_fees_at(
+    whirlpool: &Whirlpool,
+    position_address: &Pubkey,
+    position: &Position,
+    position_authority: &Pubkey,
+    position_token_account: &Pubkey,
+    token_owner_account_a: &Pubkey,
+    token_owner_account_b: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::CollectFees {
+            whirlpool: position.whirlpool,
+            position_authority: *position_authority,
+            position: *position_address,
+            position_token_account: *position_token_account,
+            token_owner_account_a: *token_owner_account_a,
+            token_vault_a: whirlpool.token_vault_a,
+            token_owner_account_b: *token_owner_account_b,
+            token_vault_b: whirlpool.token_vault_b,
+            token_program: token::ID,
+        },
+        whirlpool::instruction::CollectFees {},
+    )
+}
+
+fn collect_reward_at(
+    whirlpool: &Whirlpool,
+    position_address: &Pubkey,
+    position: &Position,
+    position_authority: &Pubkey,
+    position_token_account: &Pubkey,
+    reward_owner_account: &Pubkey,
+    reward_index: u8,
+) -> Result<Instruction> {
+    Ok(build_instruction(
+        whirlpool::accounts::CollectReward {
+            whirlpool: position.whirlpool,
+            position_authority: *position_authority,
+            position: *position_address,
+            position_token_account: *position_token_account,
+            reward_owner_account: *reward_owner_account,
+            reward_vault: reward_vault(whirlpool, reward_index)?,
+            token_program: token::ID,
+        },
+        whirlpool::instruction::CollectReward { reward_index },
+    ))
+}
+
+pub fn increase_liquidity_ix(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    params: &IncreaseLiquidityParams,
+) -> Instruction {
+    let position_address = find_position_address(&position.position_mint).0;
+    increase_liquidity_at(whirlpool, &position_address, position, params)
+}
+
+pub fn increase_bundled_position_liquidity_ix(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    bundle_index: u16,
+    params: &IncreaseLiquidityParams,
+) -> Instruction {
+    let position_address = find_bundled_position_address(&position.position_mint, bundle_index).0;
+    increase_liquidity_at(whirlpool, &position_address, position, params)
+}
+
+pub fn decrease_liquidity_ix(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    params: &DecreaseLiquidityParams,
+) -> Instruction {
+    let position_address = find_position_address(&position.position_mint).0;
+    decrease_liquidity_at(whirlpool, &position_address, position, params)
+}
+
+pub fn decrease_bundled_position_liquidity_ix(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    bundle_index: u16,
+    params: &DecreaseLiquidityParams,
+) -> Instruction {
+    let position_address = find_bundled_position_address(&position.position_mint, bundle_index).0;
+    decrease_liquidity_at(whirlpool, &position_address, position, params)
+}
+
+pub fn update_fees_and_rewards_ix(whirlpool: &Whirlpool, position: &Position) -> Instruction {
+    let position_address = find_position_address(&position.position_mint).0;
+    update_fees_and_rewards_at(whirlpool, &position_address, position)
+}
+
+pub fn update_bundled_position_fees_and_rewards_ix(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    bundle_index: u16,
+) -> Instruction {
+    let position_address = find_bundled_position_address(&position.position_mint, bundle_index).0;
+    update_fees_and_rewards_at(whirlpool, &position_address, position)
+}
+
+pub fn collect_fees_ix(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    position_authority: &Pubkey,
+    position_token_account: &Pubkey,
+    token_owner_account_a: &Pubkey,
+    token_owner_account_b: &Pubkey,
+) -> Instruction {
+    collect_fees_at(
+        whirlpool,
+        &find_position_address(&position.position_mint).0,
+        position,
+        position_authority,
+        position_token_account,
+        token_owner_account_a,
+        token_owner_account_b,
+    )
+}
+
+/// The position token account is the token account holding the position bundle.
+pub fn collect_bundled_position_fees_ix(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    bundle_index: u16,
+    position_authority: &Pubkey,
+    position_token_account: &Pubkey,
+    token_owner_account_a: &Pubkey,
+    token_owner_account_b: &Pubkey,
+) -> Instruction {
+    collect_fees_at(
+        whirlpool,
+        &find_bundled_position_address(&position.position_mint, bundle_index).0,
+        position,
+        position_authority,
+        position_token_account,
+        token_owner_account_a,
+        token_owner_account_b,
+    )
+}
+
+/// The reward vault is read from the reward info at `reward_index`.
+///
+/// # Returns
+/// - `Instruction`: The collect reward instruction
+/// - `InvalidRewardIndex`: - `reward_index` is not less than NUM_REWARDS
+pub fn collect_reward_ix(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    position_authority: &Pubkey,
+    position_token_account: &Pubkey,
+    reward_owner_account: &Pubkey,
+    reward_index: u8,
+) -> Result<Instruction> {
+    collect_reward_at(
+        whirlpool,
+        &find_position_address(&position.position_mint).0,
+        position,
+        position_authority,
+        position_token_account,
+        reward_owner_account,
+        reward_index,
+    )
+}
+
+/// The reward vault is read from the reward info at `reward_index`.
+///
+/// # Returns
+/// - `Instruction`: The collect reward instruction
+/// - `InvalidRewardIndex`: - `reward_index` is not less than NUM_REWARDS
+pub fn collect_bundled_position_reward_ix(
+    whirlpool: &Whirlpool,
+    position: &Position,
+    bundle_index: u16,
+    position_authority: &Pubkey,
+    position_token_account: &Pubkey,
+    reward_owner_account: &Pubkey,
+    reward_index: u8,
+) -> Result<Instruction> {
+    collect_reward_at(
+        whirlpool,
+        &find_bundled_position_address(&position.position_mint, bundle_index).0,
+        position,
+        position_authority,
+        position_token_account,
+        reward_owner_account,
+        reward_index,
+    )
+}
+
+pub fn close_position_ix(
+    position_mint: &Pubkey,
+    position_authority: &Pubkey,
+    position_token_account: &Pubkey,
+    receiver: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::ClosePosition {
+            position_authority: *position_authority,
+            receiver: *receiver,
+            position: find_position_address(position_mint).0,
+            position_mint: *position_mint,
+            position_token_account: *position_token_account,
+            token_program: token::ID,
+        },
+        whirlpool::instruction::ClosePosition {},
+    )
+}
+
+/// The position bundle token account is the associated token account of `owner`.
+pub fn initialize_position_bundle_ix(
+    position_bundle_mint: &Pubkey,
+    owner: &Pubkey,
+    funder: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::InitializePositionBundle {
+            position_bundle: find_position_bundle_address(position_bundle_mint).0,
+            position_bundle_mint: *position_bundle_mint,
+            position_bundle_token_account: associated_token::get_associated_token_address(
+                owner,
+                position_bundle_mint,
+            ),
+            position_bundle_owner: *owner,
+            funder: *funder,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            associated_token_program: associated_token::ID,
+        },
+        whirlpool::instruction::InitializePositionBundle {},
+    )
+}
+
+/// The position bundle token account is the associated token account of `owner`.
+pub fn initialize_position_bundle_with_metadata_ix(
+    position_bundle_mint: &Pubkey,
+    owner: &Pubkey,
+    funder: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::InitializePositionBundleWithMetadata {
+            position_bundle: find_position_bundle_address(position_bundle_mint).0,
+            position_bundle_mint: *position_bundle_mint,
+            position_bundle_metadata: find_position_bundle_metadata_address(position_bundle_mint).0,
+            position_bundle_token_account: associated_token::get_associated_token_address(
+                owner,
+                position_bundle_mint,
+            ),
+            position_bundle_owner: *owner,
+            funder: *funder,
+            metadata_update_auth: whirlpool_nft_update_auth::ID,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            associated_token_program: associated_token::ID,
+            metadata_program: mpl_token_metadata::ID,
+        },
+        whirlpool::instruction::InitializePositionBundleWithMetadata {},
+    )
+}
+
+pub fn delete_position_bundle_ix(
+    position_bundle_mint: &Pubkey,
+    position_bundle_token_account: &Pubkey,
+    owner: &Pubkey,
+    receiver: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::DeletePositionBundle {
+            position_bundle: find_position_bundle_address(position_bundle_mint).0,
+            position_bundle_mint: *position_bundle_mint,
+            position_bundle_token_account: *position_bundle_token_account,
+            position_bundle_owner: *owner,
+            receiver: *receiver,
+            token_program: token::ID,
+        },
+        whirlpool::instruction::DeletePositionBundle {},
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpenBundledPositionParams {
+    pub whirlpool: Pubkey,
+    pub position_bundle_mint: Pubkey,
+    pub position_bundle_token_account: Pubkey,
+    pub position_bundle_authority: Pubkey,
+    pub funder: Pubkey,
+    pub bundle_index: u16,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+}
+
+pub fn open_bundled_position_ix(params: &OpenBundledPositionParams) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::OpenBundledPosition {
+            bundled_position: find_bundled_position_address(
+                &params.position_bundle_mint,
+                params.bundle_index,
+            )
+            .0,
+            position_bundle: find_position_bundle_address(&params.position_bundle_mint).0,
+            position_bundle_token_account: params.position_bundle_token_account,
+            position_bundle_authority: params.position_bundle_authority,
+            whirlpool: params.whirlpool,
+            funder: params.funder,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        whirlpool::instruction::OpenBundledPosition {
+            bundle_index: params.bundle_index,
+            tick_lower_index: params.tick_lower_index,
+            tick_upper_index: params.tick_upper_index,
+        },
+    )
+}
+
+pub fn close_bundled_position_ix(
+    position_bundle_mint: &Pubkey,
+    position_bundle_token_account: &Pubkey,
+    position_bundle_authority: &Pubkey,
+    receiver: &Pubkey,
+    bundle_index: u16,
+) -> Instruction {
+    build_instruction(
+        whirlpool::accounts::CloseBundledPosition {
+            bundled_position: find_bundled_position_address(position_bundle_mint, bundle_index).0,
+            position_bundle: find_position_bundle_address(position_bundle_mint).0,
+            position_bundle_token_account: *position_bundle_token_account,
+            position_bundle_authority: *position_bundle_authority,
+            receiver: *receiver,
+        },
+        whirlpool::instruction::CloseBundledPosition { bundle_index },
+    )
+}
+
+/// Build a swap from the params of a one-hop route. The tick arrays are derived from the
+/// current tick of `whirlpool`; further tick arrays can be appended as remaining accounts.
+pub fn swap_ix(
+    whirlpool: &Whirlpool,
+    params: &SwapParams,
+    token_authority: &Pubkey,
+    token_owner_account_a: &Pubkey,
+    token_owner_account_b: &Pubkey,
+) -> Instruction {
+    let [tick_array_0, tick_array_1, tick_array_2] = get_swap_tick_array_addresses(
+        &params.whirlpool,
+        whirlpool.tick_current_index,
+        whirlpool.tick_spacing,
+        params.a_to_b,
+    );
+    let oracle = find_oracle_address(&params.whirlpool).0;
+    let instruction = build_instruction(
+        whirlpool::accounts::Swap {
+            token_program: token::ID,
+            token_authority: *token_authority,
+            whirlpool: params.whirlpool,
+            token_owner_account_a: *token_owner_account_a,
+            token_vault_a: whirlpool.token_vault_a,
+            token_owner_account_b: *token_owner_account_b,
+            token_vault_b: whirlpool.token_vault_b,
+            tick_array_0,
+            tick_array_1,
+            tick_array_2,
+            oracle,
+        },
+        whirlpool::instruction::Swap {
+            amount: params.amount,
+            other_amount_threshold: params.other_amount_threshold,
+            sqrt_price_limit: params.sqrt_price_limit,
+            amount_specified_is_input: params.amount_specified_is_input,
+            a_to_b: params.a_to_b,
+        },
+    );
+    record_oracle_observations(instruction, &[oracle])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TwoHopSwapTokenAccounts {
+    pub token_authority: Pubkey,
+    pub token_owner_account_one_a: Pubkey,
+    pub token_owner_account_one_b: Pubkey,
+    pub token_owner_account_two_a: Pubkey,
+    pub token_owner_account_two_b: Pubkey,
+}
+
+/// Build a two-hop swap from the params of a two-hop route. The tick arrays of each
+/// Whirlpool are derived from its current tick.
+pub fn two_hop_swap_ix(
+    whirlpool_one: &Whirlpool,
+    whirlpool_two: &Whirlpool,
+    params: &TwoHopSwapParams,
+    token_accounts: &TwoHopSwapTokenAccounts,
+) -> Instruction {
+    let [tick_array_one_0, tick_array_one_1, tick_array_one_2] = get_swap_tick_array_addresses(
+        &params.whirlpool_one,
+        whirlpool_one.tick_current_index,
+        whirlpool_one.tick_spacing,
+        params.a_to_b_one,
+    );
+    let [tick_array_two_0, tick_array_two_1, tick_array_two_2] = get_swap_tick_array_addresses(
+        &params.whirlpool_two,
+        whirlpool_two.tick_current_index,
+        whirlpool_two.tick_spacing,
+        params.a_to_b_two,
+    );
+    let oracle_one = find_oracle_address(&params.whirlpool_one).0;
+    let oracle_two = find_oracle_address(&params.whirlpool_two).0;
+    let instruction = build_instruction(
+        whirlpool::accounts::TwoHopSwap {
+            token_program: token::ID,
+            token_authority: token_accounts.token_authority,
+            whirlpool_one: params.whirlpool_one,
+            whirlpool_two: params.whirlpool_two,
+            token_owner_account_one_a: token_accounts.token_owner_account_one_a,
+            token_vault_one_a: whirlpool_one.token_vault_a,
+            token_owner_account_one_b: token_accounts.token_owner_account_one_b,
+            token_vault_one_b: whirlpool_one.token_vault_b,
+            token_owner_account_two_a: token_accounts.token_owner_account_two_a,
+            token_vault_two_a: whirlpool_two.token_vault_a,
+            token_owner_account_two_b: token_accounts.token_owner_account_two_b,
+            token_vault_two_b: whirlpool_two.token_vault_b,
+            tick_array_one_0,
+            tick_array_one_1,
+            tick_array_one_2,
+            tick_array_two_0,
+            tick_array_two_1,
+            tick_array_two_2,
+            oracle_one,
+            oracle_two,
+        },
+        whirlpool::instruction::TwoHopSwap {
+            amount: params.amount,
+            other_amount_threshold: params.other_amount_threshold,
+            amount_specified_is_input: params.amount_specified_is_input,
+            a_to_b_one: params.a_to_b_one,
+            a_to_b_two: params.a_to_b_two,
+            sqrt_price_limit_one: params.sqrt_price_limit_one,
+            sqrt_price_limit_two: params.sqrt_price_limit_two,
+        },
+    );
+    record_oracle_observations(instruction, &[oracle_one, oracle_two])
+}
+
+#[derive(Clone, Copy)]
+pub struct RouteSwapHopParams<'a> {
+    pub address: Pubkey,
+    pub whirlpool: &'a Whirlpool,
+    pub a_to_b: bool,
+    pub sqrt_price_limit: u128,
+    pub token_owner_account_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+}
+
+/// Build a route swap through `hops`, in route order. The accounts of each hop are appended
+/// as remaining accounts in the layout the route_swap instruction expects.
+pub fn route_swap_ix(
+    token_authority: &Pubkey,
+    amount: u64,
+    other_amount_threshold: u64,
+    amount_specified_is_input: bool,
+    hops: &[RouteSwapHopParams],
+) -> Instruction {
+    let mut instruction = build_instruction(
+        whirlpool::accounts::RouteSwap {
+            token_program: token::ID,
+            token_authority: *token_authority,
+        },
+        whirlpool::instruction::RouteSwap {
+            amount,
+            other_amount_threshold,
+            amount_specified_is_input,
+            hops: hops
+                .iter()
+                .map(|hop| RouteSwapHop {
+                    a_to_b: hop.a_to_b,
+                    sqrt_price_limit: hop.sqrt_price_limit,
+                    oracle_bump: find_oracle_address(&hop.address).1,
+                })
+                .collect(),
+        },
+    );
+
+    for hop in hops {
+        let tick_arrays = get_swap_tick_array_addresses(
+            &hop.address,
+            hop.whirlpool.tick_current_index,
+            hop.whirlpool.tick_spacing,
+            hop.a_to_b,
+        );
+        instruction.accounts.extend([
+            AccountMeta::new(hop.address, false),
+            AccountMeta::new(hop.token_owner_account_a, false),
+            AccountMeta::new(hop.whirlpool.token_vault_a, false),
+            AccountMeta::new(hop.token_owner_account_b, false),
+            AccountMeta::new(hop.whirlpool.token_vault_b, false),
+            AccountMeta::new(tick_arrays[0], false),
+            AccountMeta::new(tick_arrays[1], false),
+            AccountMeta::new(tick_arrays[2], false),
+            AccountMeta::new(find_oracle_address(&hop.address).0, false),
+        ]);
+    }
+    instruction
+}
+
+#[cfg(test)]
+mod instructions_tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use whirlpool::math::{MAX_SQRT_PRICE_X64, MIN_SQRT_PRICE_X64};
+
+    fn key(seed: u8) -> Pubkey {
+        Pubkey::new_from_array([seed; 32])
+    }
+
+    fn test_whirlpool(tick_current_index: i32) -> Whirlpool {
+        Whirlpool {
+            whirlpools_config: key(1),
+            tick_spacing: 64,
+            tick_current_index,
+            token_mint_a: key(2),
+            token_vault_a: key(3),
+            token_mint_b: key(4),
+            token_vault_b: key(5),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_swap_ix() {
+        let whirlpool = test_whirlpool(100);
+        let params = SwapParams {
+            whirlpool: key(10),
+            amount: 1_000,
+            other_amount_threshold: 990,
+            sqrt_price_limit: MIN_SQRT_PRICE_X64,
+            amount_specified_is_input: true,
+            a_to_b: true,
+        };
+        let ix = swap_ix(&whirlpool, &params, &key(20), &key(21), &key(22));
+
+        let tick_arrays = get_swap_tick_array_addresses(&key(10), 100, 64, true);
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new_readonly(token::ID, false),
+                AccountMeta::new_readonly(key(20), true),
+                AccountMeta::new(key(10), false),
+                AccountMeta::new(key(21), false),
+                AccountMeta::new(key(3), false),
+                AccountMeta::new(key(22), false),
+                AccountMeta::new(key(5), false),
+                AccountMeta::new(tick_arrays[0], false),
+                AccountMeta::new(tick_arrays[1], false),
+                AccountMeta::new(tick_arrays[2], false),
+                AccountMeta::new(find_oracle_address(&key(10)).0, false),
+            ]
+        );
+        assert_eq!(ix.program_id, whirlpool::ID);
+        assert_eq!(ix.data[..8], whirlpool::instruction::Swap::DISCRIMINATOR);
+    }
+
+    #[test]
+    fn test_two_hop_swap_ix() {
+        let whirlpool_one = test_whirlpool(100);
+        let whirlpool_two = test_whirlpool(-6000);
+        let params = TwoHopSwapParams {
+            whirlpool_one: key(10),
+            whirlpool_two: key(11),
+            amount: 1_000,
+            other_amount_threshold: 990,
+            amount_specified_is_input: true,
+            a_to_b_one: true,
+            a_to_b_two: false,
+            sqrt_price_limit_one: MIN_SQRT_PRICE_X64,
+            sqrt_price_limit_two: MAX_SQRT_PRICE_X64,
+        };
+        let token_accounts = TwoHopSwapTokenAccounts {
+            token_authority: key(20),
+            token_owner_account_one_a: key(21),
+            token_owner_account_one_b: key(22),
+            token_owner_account_two_a: key(23),
+            token_owner_account_two_b: key(24),
+        };
+        let ix = two_hop_swap_ix(&whirlpool_one, &whirlpool_two, &params, &token_accounts);
+
+        let tick_arrays_two = get_swap_tick_array_addresses(&key(11), -6000, 64, false);
+        assert_eq!(ix.accounts.len(), 20);
+        assert_eq!(ix.accounts[2].pubkey, key(10));
+        assert_eq!(ix.accounts[3].pubkey, key(11));
+        assert_eq!(ix.accounts[15].pubkey, tick_arrays_two[0]);
+        assert_eq!(ix.accounts[17].pubkey, tick_arrays_two[2]);
+        assert_eq!(ix.accounts[18].pubkey, find_oracle_address(&key(10)).0);
+        assert_eq!(ix.accounts[19].pubkey, find_oracle_address(&key(11)).0);
+
+        let data = whirlpool::instruction::TwoHopSwap::try_from_slice(&ix.data[8..]).unwrap();
+        assert!(!data.a_to_b_two);
+        assert_eq!(data.sqrt_price_limit_two, MAX_SQRT_PRICE_X64);
+    }
+
+    #[test]
+    fn test_route_swap_ix() {
+        let whirlpool_one = test_whirlpool(100);
+        let whirlpool_two = test_whirlpool(-6000);
+        let hop = |address, whirlpool, a_to_b| RouteSwapHopParams {
+            address,
+            whirlpool,
+            a_to_b,
+            sqrt_price_limit: if a_to_b {
+                MIN_SQRT_PRICE_X64
+            } else {
+                MAX_SQRT_PRICE_X64
+            },
+            token_owner_account_a: key(21),
+            token_owner_account_b: key(22),
+        };
+        let hops = [
+            hop(key(10), &whirlpool_one, true),
+            hop(key(11), &whirlpool_two, false),
+        ];
+        let ix = route_swap_ix(&key(20), 1_000, 990, true, &hops);
+
+        assert_eq!(ix.accounts.len(), 2 + 2 * 9);
+        assert_eq!(ix.accounts[2].pubkey, key(10));
+        assert_eq!(ix.accounts[4].pubkey, whirlpool_one.token_vault_a);
+        assert_eq!(ix.accounts[11], AccountMeta::new(key(11), false));
+        assert_eq!(ix.accounts[19].pubkey, find_oracle_address(&key(11)).0);
+
+        let data = whirlpool::instruction::RouteSwap::try_from_slice(&ix.data[8..]).unwrap();
+        assert_eq!(data.hops.len(), 2);
+        assert!(data.hops[0].a_to_b && !data.hops[1].a_to_b);
+        assert_eq!(data.hops[1].oracle_bump, find_oracle_address(&key(11)).1);
+    }
+
+    #[test]
+    fn test_increase_liquidity_ix_derives_tick_arrays() {
+        let whirlpool = test_whirlpool(0);
+        let position = Position {
+            whirlpool: key(10),
+            position_mint: key(30),
+            tick_lower_index: -128,
+            tick_upper_index: 5632,
+            ..Default::default()
+        };
+        let params = IncreaseLiquidityParams {
+            position_authority: key(20),
+            position_token_account: key(31),
+            token_owner_account_a: key(21),
+            token_owner_account_b: key(22),
+            liquidity_amount: 1_000,
+            token_max_a: 10,
+            token_max_b: 10,
+        };
+        let position_address = find_position_address(&key(30)).0;
+        let ix = increase_liquidity_ix(&whirlpool, &position, &params);
+
+        assert_eq!(ix.accounts[0].pubkey, key(10));
+        assert_eq!(ix.accounts[3].pubkey, position_address);
+        assert_eq!(
+            ix.accounts[9].pubkey,
+            find_tick_array_address(&key(10), -5632).0
+        );
+        assert_eq!(
+            ix.accounts[10].pubkey,
+            find_tick_array_address(&key(10), 5632).0
+        );
+        assert_eq!(
+            ix.accounts[11],
+            AccountMeta::new(find_oracle_address(&key(10)).0, false)
+        );
+    }
+
+    #[test]
+    fn test_position_ixs_use_bundled_position_address() {
+        let whirlpool = test_whirlpool(0);
+        let position = Position {
+            whirlpool: key(10),
+            position_mint: key(30),
+            tick_lower_index: -128,
+            tick_upper_index: 128,
+            ..Default::default()
+        };
+        let bundled_position = find_bundled_position_address(&key(30), 7).0;
+
+        let ix = increase_bundled_position_liquidity_ix(
+            &whirlpool,
+            &position,
+            7,
+            &IncreaseLiquidityParams {
+                position_authority: key(20),
+                position_token_account: key(31),
+                token_owner_account_a: key(21),
+                token_owner_account_b: key(22),
+                liquidity_amount: 1_000,
+                token_max_a: 10,
+                token_max_b: 10,
+            },
+        );
+        assert_eq!(ix.accounts[3], AccountMeta::new(bundled_position, false));
+
+        let ix = update_bundled_position_fees_and_rewards_ix(&whirlpool, &position, 7);
+        assert_eq!(ix.accounts[1], AccountMeta::new(bundled_position, false));
+
+        let ix = collect_bundled_position_fees_ix(
+            &whirlpool,
+            &position,
+            7,
+            &key(20),
+            &key(31),
+            &key(21),
+            &key(22),
+        );
+        assert_eq!(ix.accounts[2], AccountMeta::new(bundled_position, false));
+
+        let ix = collect_bundled_position_reward_ix(
+            &whirlpool,
+            &position,
+            7,
+            &key(20),
+            &key(31),
+            &key(23),
+            0,
+        )
+        .unwrap();
+        assert_eq!(ix.accounts[2], AccountMeta::new(bundled_position, false));
+    }
+
+    #[test]
+    fn test_reward_ixs_reject_invalid_reward_index() {
+        let mut whirlpool = test_whirlpool(0);
+        whirlpool.reward_infos[2].vault = key(40);
+        let position = Position {
+            whirlpool: key(10),
+            ..Default::default()
+        };
+
+        let ix = set_reward_emissions_ix(&whirlpool, &key(20), 2, 1 << 64).unwrap();
+        assert_eq!(ix.accounts[2].pubkey, key(40));
+        assert_eq!(
+            set_reward_emissions_ix(&whirlpool, &key(20), 3, 1 << 64).unwrap_err(),
+            ErrorCode::InvalidRewardIndex.into()
+        );
+        assert_eq!(
+            collect_reward_ix(&whirlpool, &position, &key(20), &key(31), &key(23), 3,).unwrap_err(),
+            ErrorCode::InvalidRewardIndex.into()
+        );
+    }
+
+    #[test]
+    fn test_open_position_ix_derives_accounts() {
+        let ix = open_position_ix(&key(10), &key(20), &key(21), &key(30), -128, 128);
+        let (position, position_bump) = find_position_address(&key(30));
+
+        assert_eq!(ix.accounts[2], AccountMeta::new(position, false));
+        assert_eq!(ix.accounts[3], AccountMeta::new(key(30), true));
+        assert_eq!(
+            ix.accounts[4].pubkey,
+            associated_token::get_associated_token_address(&key(21), &key(30))
+        );
+        let data = whirlpool::instruction::OpenPosition::try_from_slice(&ix.data[8..]).unwrap();
+        assert_eq!(data.bumps.position_bump, position_bump);
+    }
+}
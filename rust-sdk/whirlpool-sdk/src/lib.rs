@@ -0,0 +1,16 @@
+//! Off-chain helpers for interacting with the Whirlpool program from Rust.
+//!
+//! The helpers reuse the program's own math and state types, so results computed here match
+//! what the program computes on-chain.
+
+pub mod instructions;
+pub mod pda;
+pub mod quote;
+pub mod router;
+pub mod tick_array;
+
+pub use instructions::*;
+pub use pda::*;
+pub use quote::*;
+pub use router::*;
+pub use tick_array::*;
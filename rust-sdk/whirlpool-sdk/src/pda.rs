@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+const PDA_WHIRLPOOL_SEED: &[u8] = b"whirlpool";
+const PDA_POSITION_SEED: &[u8] = b"position";
+const PDA_TICK_ARRAY_SEED: &[u8] = b"tick_array";
+const PDA_FEE_TIER_SEED: &[u8] = b"fee_tier";
+const PDA_ORACLE_SEED: &[u8] = b"oracle";
+const PDA_POSITION_BUNDLE_SEED: &[u8] = b"position_bundle";
+const PDA_BUNDLED_POSITION_SEED: &[u8] = b"bundled_position";
+
+// Program derived addresses of the Whirlpool program accounts.
+// Each function returns the address and its bump seed.
+
+pub fn find_whirlpool_address(
+    whirlpools_config: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    tick_spacing: u16,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PDA_WHIRLPOOL_SEED,
+            whirlpools_config.as_ref(),
+            token_mint_a.as_ref(),
+            token_mint_b.as_ref(),
+            &tick_spacing.to_le_bytes(),
+        ],
+        &whirlpool::ID,
+    )
+}
+
+pub fn find_position_address(position_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PDA_POSITION_SEED, position_mint.as_ref()], &whirlpool::ID)
+}
+
+pub fn find_position_metadata_address(position_mint: &Pubkey) -> (Pubkey, u8) {
+    mpl_token_metadata::accounts::Metadata::find_pda(position_mint)
+}
+
+pub fn find_tick_array_address(whirlpool: &Pubkey, start_tick_index: i32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PDA_TICK_ARRAY_SEED,
+            whirlpool.as_ref(),
+            start_tick_index.to_string().as_bytes(),
+        ],
+        &whirlpool::ID,
+    )
+}
+
+pub fn find_fee_tier_address(whirlpools_config: &Pubkey, tick_spacing: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PDA_FEE_TIER_SEED,
+            whirlpools_config.as_ref(),
+            &tick_spacing.to_le_bytes(),
+        ],
+        &whirlpool::ID,
+    )
+}
+
+pub fn find_oracle_address(whirlpool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PDA_ORACLE_SEED, whirlpool.as_ref()], &whirlpool::ID)
+}
+
+pub fn find_position_bundle_address(position_bundle_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PDA_POSITION_BUNDLE_SEED, position_bundle_mint.as_ref()],
+        &whirlpool::ID,
+    )
+}
+
+pub fn find_position_bundle_metadata_address(position_bundle_mint: &Pubkey) -> (Pubkey, u8) {
+    mpl_token_metadata::accounts::Metadata::find_pda(position_bundle_mint)
+}
+
+pub fn find_bundled_position_address(
+    position_bundle_mint: &Pubkey,
+    bundle_index: u16,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PDA_BUNDLED_POSITION_SEED,
+            position_bundle_mint.as_ref(),
+            bundle_index.to_string().as_bytes(),
+        ],
+        &whirlpool::ID,
+    )
+}
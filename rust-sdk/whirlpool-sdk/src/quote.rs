@@ -0,0 +1,254 @@
+use std::cell::RefCell;
+
+use anchor_lang::prelude::*;
+use whirlpool::{
+    errors::ErrorCode,
+    manager::swap_manager::swap,
+    state::{TickArray, Whirlpool},
+    util::SwapTickSequence,
+};
+
+// Number of tick arrays the swap instruction takes as named accounts.
+// Additional tick arrays are passed through remaining accounts.
+pub const MAX_SWAP_TICK_ARRAYS: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwapQuote {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    // Total fee charged on the input token, including the protocol fee
+    pub fee_amount: u64,
+    pub end_sqrt_price: u128,
+    pub end_tick_index: i32,
+    // Number of initialized ticks crossed during the swap
+    pub ticks_crossed: u32,
+    // Start tick indexes of the tick arrays the swap traversed, in traversal order
+    pub tick_arrays_used: Vec<i32>,
+}
+
+/// Quote a swap against the given Whirlpool state by running the same swap loop as the
+/// on-chain swap instruction. The provided state is not modified.
+///
+/// # Parameters
+/// - `whirlpool` - The Whirlpool to swap in
+/// - `tick_arrays` - The tick arrays that would be passed to the swap instruction, in order,
+///   including any passed through remaining accounts
+/// - `amount` - The amount of input or output token to swap (depending on amount_specified_is_input)
+/// - `sqrt_price_limit` - The maximum/minimum price the swap will swap to
+/// - `amount_specified_is_input` - If true, `amount` represents the input token of the swap
+/// - `a_to_b` - The direction of the swap. True if swapping from A to B
+/// - `timestamp` - The timestamp to accrue rewards up to, must not precede the last reward update
+///
+/// # Returns
+/// - `SwapQuote`: The amounts, fee and end state of the swap
+/// - `InvalidTickArraySequence`: - No tick arrays were provided
+/// - Any error the swap instruction would fail with for the same inputs
+pub fn swap_quote(
+    whirlpool: &Whirlpool,
+    tick_arrays: &[TickArray],
+    amount: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool,
+    timestamp: u64,
+) -> Result<SwapQuote> {
+    if tick_arrays.is_empty() {
+        return Err(ErrorCode::InvalidTickArraySequence.into());
+    }
+
+    // The swap loop mutates the tick arrays it crosses, so it runs against copies
+    let cells: Vec<RefCell<TickArray>> = tick_arrays.iter().map(|ta| RefCell::new(*ta)).collect();
+    let mut swap_tick_sequence = SwapTickSequence {
+        arrays: cells.iter().map(|cell| cell.borrow_mut()).collect(),
+    };
+
+    let swap_update = swap(
+        whirlpool,
+        &mut swap_tick_sequence,
+        amount,
+        sqrt_price_limit,
+        amount_specified_is_input,
+        a_to_b,
+        timestamp,
+    )?;
+
+    let (amount_in, amount_out) = if a_to_b {
+        (swap_update.amount_a, swap_update.amount_b)
+    } else {
+        (swap_update.amount_b, swap_update.amount_a)
+    };
+
+    let tick_arrays_used = tick_arrays[..=swap_update.last_tick_array_index]
+        .iter()
+        .map(|ta| ta.start_tick_index)
+        .collect();
+
+    Ok(SwapQuote {
+        amount_in,
+        amount_out,
+        fee_amount: swap_update.fee_amount,
+        end_sqrt_price: swap_update.next_sqrt_price,
+        end_tick_index: swap_update.next_tick_index,
+        ticks_crossed: swap_update.ticks_crossed,
+        tick_arrays_used,
+    })
+}
+
+#[cfg(test)]
+mod swap_quote_tests {
+    use super::*;
+    use whirlpool::{
+        math::{compute_swap, sqrt_price_from_tick_index, MAX_SQRT_PRICE_X64},
+        state::{Tick, TICK_ARRAY_SIZE},
+    };
+
+    const TICK_SPACING: u16 = 64;
+    const LIQUIDITY: u128 = 1_000_000_000;
+
+    fn test_whirlpool() -> Whirlpool {
+        Whirlpool {
+            tick_spacing: TICK_SPACING,
+            fee_rate: 3000,
+            protocol_fee_rate: 300,
+            liquidity: LIQUIDITY,
+            sqrt_price: sqrt_price_from_tick_index(0),
+            tick_current_index: 0,
+            ..Default::default()
+        }
+    }
+
+    fn tick_array(start_tick_index: i32) -> TickArray {
+        TickArray {
+            start_tick_index,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_tick_arrays() {
+        let result = swap_quote(
+            &test_whirlpool(),
+            &[],
+            100,
+            MAX_SQRT_PRICE_X64,
+            true,
+            false,
+            0,
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            ErrorCode::InvalidTickArraySequence.into()
+        );
+    }
+
+    #[test]
+    fn test_matches_single_step_computation() {
+        let whirlpool = test_whirlpool();
+        let ticks_in_array = TICK_ARRAY_SIZE * TICK_SPACING as i32;
+        let quote = swap_quote(
+            &whirlpool,
+            &[tick_array(0), tick_array(ticks_in_array)],
+            1_000,
+            MAX_SQRT_PRICE_X64,
+            true,
+            false,
+            0,
+        )
+        .unwrap();
+
+        let computation = compute_swap(
+            1_000,
+            whirlpool.fee_rate,
+            LIQUIDITY,
+            whirlpool.sqrt_price,
+            // With no initialized ticks, the search ends at the last tick of the last array
+            sqrt_price_from_tick_index(2 * ticks_in_array - TICK_SPACING as i32),
+            true,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(quote.amount_in, 1_000);
+        assert_eq!(quote.amount_out, computation.amount_out);
+        assert_eq!(quote.fee_amount, computation.fee_amount);
+        assert_eq!(quote.end_sqrt_price, computation.next_price);
+        assert_eq!(quote.ticks_crossed, 0);
+        assert_eq!(quote.tick_arrays_used, vec![0, ticks_in_array]);
+    }
+
+    #[test]
+    fn test_crosses_initialized_tick() {
+        let whirlpool = test_whirlpool();
+        let mut array = tick_array(0);
+        // Position ends at tick 128, leaving no liquidity above it
+        array.ticks[2] = Tick {
+            initialized: true,
+            liquidity_net: -(LIQUIDITY as i128),
+            liquidity_gross: LIQUIDITY,
+            ..Default::default()
+        };
+        let tick_arrays = [array];
+
+        let sqrt_price_limit = sqrt_price_from_tick_index(128);
+        let quote = swap_quote(
+            &whirlpool,
+            &tick_arrays,
+            u64::MAX / 2,
+            sqrt_price_limit,
+            true,
+            false,
+            0,
+        )
+        .unwrap();
+
+        assert!(quote.amount_in > 0 && quote.amount_in < u64::MAX / 2);
+        assert!(quote.fee_amount > 0);
+        assert_eq!(quote.end_sqrt_price, sqrt_price_limit);
+        assert_eq!(quote.end_tick_index, 128);
+        assert_eq!(quote.ticks_crossed, 1);
+        assert_eq!(quote.tick_arrays_used, vec![0]);
+        // The caller's tick arrays are left untouched
+        assert!(tick_arrays[0].ticks[2] == array.ticks[2]);
+    }
+
+    #[test]
+    fn test_traverses_more_than_three_tick_arrays() {
+        let whirlpool = Whirlpool {
+            tick_spacing: 1,
+            ..test_whirlpool()
+        };
+        let ticks_in_array = TICK_ARRAY_SIZE;
+        let tick_arrays: Vec<TickArray> = (0..5).map(|i| tick_array(i * ticks_in_array)).collect();
+
+        let sqrt_price_limit = sqrt_price_from_tick_index(4 * ticks_in_array + 10);
+        let quote = swap_quote(
+            &whirlpool,
+            &tick_arrays,
+            u64::MAX / 2,
+            sqrt_price_limit,
+            true,
+            false,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(quote.end_sqrt_price, sqrt_price_limit);
+        assert_eq!(quote.end_tick_index, 4 * ticks_in_array + 10);
+        assert_eq!(quote.tick_arrays_used, vec![0, 88, 176, 264, 352]);
+
+        // The same swap fails with only the three named tick arrays
+        let result = swap_quote(
+            &whirlpool,
+            &tick_arrays[..3],
+            u64::MAX / 2,
+            sqrt_price_limit,
+            true,
+            false,
+            0,
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            ErrorCode::TickArraySequenceInvalidIndex.into()
+        );
+    }
+}
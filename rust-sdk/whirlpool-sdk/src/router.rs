@@ -0,0 +1,643 @@
+use std::collections::{HashMap, HashSet};
+
+use anchor_lang::prelude::*;
+use whirlpool::{
+    errors::ErrorCode,
+    math::{MAX_SQRT_PRICE_X64, MIN_SQRT_PRICE_X64},
+    state::{TickArray, Whirlpool, TICK_ARRAY_SIZE},
+};
+
+use crate::{get_start_tick_index, swap_quote, SwapQuote};
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// A Whirlpool that can be routed through, along with the tick arrays loaded for it.
+#[derive(Clone)]
+pub struct RoutePool {
+    pub address: Pubkey,
+    pub whirlpool: Whirlpool,
+    // Tick arrays of the pool in any order. Swaps can only traverse consecutive tick arrays
+    // starting from the one containing the current tick, so gaps cut a swap short.
+    pub tick_arrays: Vec<TickArray>,
+}
+
+impl RoutePool {
+    // The consecutive tick arrays a swap in the given direction would traverse, in order
+    fn swap_tick_arrays(&self, a_to_b: bool) -> Vec<TickArray> {
+        let tick_spacing = self.whirlpool.tick_spacing;
+        let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+        let by_start_index: HashMap<i32, &TickArray> = self
+            .tick_arrays
+            .iter()
+            .map(|ta| (ta.start_tick_index, ta))
+            .collect();
+
+        // See get_swap_tick_array_start_indexes for the shift applied to b_to_a swaps
+        let shift = if a_to_b { 0 } else { tick_spacing as i32 };
+        let step = if a_to_b {
+            -ticks_in_array
+        } else {
+            ticks_in_array
+        };
+
+        let mut tick_arrays = Vec::new();
+        let mut start_tick_index =
+            get_start_tick_index(self.whirlpool.tick_current_index + shift, tick_spacing, 0);
+        while let Some(tick_array) = start_tick_index.and_then(|s| by_start_index.get(&s)) {
+            tick_arrays.push(**tick_array);
+            start_tick_index = start_tick_index.map(|s| s + step);
+        }
+        tick_arrays
+    }
+}
+
+/// A single swap of a path: the index of the pool in the graph and the swap direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathHop {
+    pub pool_index: usize,
+    pub a_to_b: bool,
+}
+
+/// A path of one or two hops from an input mint to an output mint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub hops: Vec<PathHop>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteHopQuote {
+    pub whirlpool: Pubkey,
+    pub a_to_b: bool,
+    pub sqrt_price_limit: u128,
+    pub quote: SwapQuote,
+}
+
+/// A quoted path. The output of each hop is fully consumed as the input of the next hop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteQuote {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub amount_specified_is_input: bool,
+    pub hops: Vec<RouteHopQuote>,
+}
+
+/// Arguments of the two_hop_swap instruction, along with the two Whirlpools to swap through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TwoHopSwapParams {
+    pub whirlpool_one: Pubkey,
+    pub whirlpool_two: Pubkey,
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub amount_specified_is_input: bool,
+    pub a_to_b_one: bool,
+    pub a_to_b_two: bool,
+    pub sqrt_price_limit_one: u128,
+    pub sqrt_price_limit_two: u128,
+}
+
+/// Arguments of the swap instruction, along with the Whirlpool to swap in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapParams {
+    pub whirlpool: Pubkey,
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit: u128,
+    pub amount_specified_is_input: bool,
+    pub a_to_b: bool,
+}
+
+impl RouteQuote {
+    /// The threshold on the variable side of the route after allowing for `slippage_bps`:
+    /// the minimum output for exact-in routes, the maximum input for exact-out routes.
+    pub fn other_amount_threshold(&self, slippage_bps: u16) -> u64 {
+        let slippage_bps = slippage_bps as u128;
+        if self.amount_specified_is_input {
+            let threshold = self.amount_out as u128
+                * (BPS_DENOMINATOR - slippage_bps.min(BPS_DENOMINATOR))
+                / BPS_DENOMINATOR;
+            threshold as u64
+        } else {
+            let threshold = (self.amount_in as u128 * (BPS_DENOMINATOR + slippage_bps))
+                .div_ceil(BPS_DENOMINATOR);
+            threshold.min(u64::MAX as u128) as u64
+        }
+    }
+
+    fn amount(&self) -> u64 {
+        if self.amount_specified_is_input {
+            self.amount_in
+        } else {
+            self.amount_out
+        }
+    }
+
+    /// Map a one-hop route onto the arguments of the swap instruction.
+    /// Returns None if the route does not have exactly one hop.
+    pub fn swap_params(&self, slippage_bps: u16) -> Option<SwapParams> {
+        match self.hops.as_slice() {
+            [hop] => Some(SwapParams {
+                whirlpool: hop.whirlpool,
+                amount: self.amount(),
+                other_amount_threshold: self.other_amount_threshold(slippage_bps),
+                sqrt_price_limit: hop.sqrt_price_limit,
+                amount_specified_is_input: self.amount_specified_is_input,
+                a_to_b: hop.a_to_b,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Map a two-hop route onto the arguments of the two_hop_swap instruction.
+    /// Returns None if the route does not have exactly two hops.
+    pub fn two_hop_swap_params(&self, slippage_bps: u16) -> Option<TwoHopSwapParams> {
+        match self.hops.as_slice() {
+            [hop_one, hop_two] => Some(TwoHopSwapParams {
+                whirlpool_one: hop_one.whirlpool,
+                whirlpool_two: hop_two.whirlpool,
+                amount: self.amount(),
+                other_amount_threshold: self.other_amount_threshold(slippage_bps),
+                amount_specified_is_input: self.amount_specified_is_input,
+                a_to_b_one: hop_one.a_to_b,
+                a_to_b_two: hop_two.a_to_b,
+                sqrt_price_limit_one: hop_one.sqrt_price_limit,
+                sqrt_price_limit_two: hop_two.sqrt_price_limit,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// A graph of token mints connected by the Whirlpools that trade them.
+pub struct PoolGraph {
+    pools: Vec<RoutePool>,
+    // Indexes of the pools that trade each mint
+    pools_by_mint: HashMap<Pubkey, Vec<usize>>,
+}
+
+impl PoolGraph {
+    pub fn new(pools: Vec<RoutePool>) -> Self {
+        let mut pools_by_mint: HashMap<Pubkey, Vec<usize>> = HashMap::new();
+        for (i, pool) in pools.iter().enumerate() {
+            pools_by_mint
+                .entry(pool.whirlpool.token_mint_a)
+                .or_default()
+                .push(i);
+            pools_by_mint
+                .entry(pool.whirlpool.token_mint_b)
+                .or_default()
+                .push(i);
+        }
+        Self {
+            pools,
+            pools_by_mint,
+        }
+    }
+
+    pub fn pools(&self) -> &[RoutePool] {
+        &self.pools
+    }
+
+    // The mint received when swapping `mint` through the pool, and the swap direction
+    fn swap_through(&self, pool_index: usize, mint: &Pubkey) -> Option<(Pubkey, bool)> {
+        let whirlpool = &self.pools[pool_index].whirlpool;
+        if whirlpool.token_mint_a == *mint {
+            Some((whirlpool.token_mint_b, true))
+        } else if whirlpool.token_mint_b == *mint {
+            Some((whirlpool.token_mint_a, false))
+        } else {
+            None
+        }
+    }
+
+    /// Enumerate all one-hop and two-hop paths from `input_mint` to `output_mint`.
+    /// Two-hop paths never pass through the same pool twice.
+    pub fn get_paths(&self, input_mint: &Pubkey, output_mint: &Pubkey) -> Vec<Path> {
+        let mut paths = Vec::new();
+        if input_mint == output_mint {
+            return paths;
+        }
+
+        let empty = Vec::new();
+        let input_pools = self.pools_by_mint.get(input_mint).unwrap_or(&empty);
+        for &pool_one in input_pools {
+            let (intermediate_mint, a_to_b_one) = match self.swap_through(pool_one, input_mint) {
+                Some(hop) => hop,
+                None => continue,
+            };
+            let hop_one = PathHop {
+                pool_index: pool_one,
+                a_to_b: a_to_b_one,
+            };
+
+            if intermediate_mint == *output_mint {
+                paths.push(Path {
+                    hops: vec![hop_one],
+                });
+                continue;
+            }
+
+            let intermediate_pools = self.pools_by_mint.get(&intermediate_mint).unwrap_or(&empty);
+            for &pool_two in intermediate_pools {
+                if pool_two == pool_one {
+                    continue;
+                }
+                if let Some((mint, a_to_b_two)) = self.swap_through(pool_two, &intermediate_mint) {
+                    if mint == *output_mint {
+                        paths.push(Path {
+                            hops: vec![
+                                hop_one,
+                                PathHop {
+                                    pool_index: pool_two,
+                                    a_to_b: a_to_b_two,
+                                },
+                            ],
+                        });
+                    }
+                }
+            }
+        }
+        paths
+    }
+
+    /// Quote a path with the swap loop of the program. Exact-in paths are quoted from the first
+    /// hop to the last, exact-out paths from the last hop to the first.
+    ///
+    /// # Returns
+    /// - `RouteQuote`: The amounts and per-hop quotes of the route
+    /// - `IntermediateTokenAmountMismatch`: A hop only partially fills the amount of the next hop
+    /// - Any error the swap instruction would fail with on one of the hops
+    pub fn quote_path(
+        &self,
+        path: &Path,
+        amount: u64,
+        amount_specified_is_input: bool,
+        timestamp: u64,
+    ) -> Result<RouteQuote> {
+        let quote_hop = |hop: &PathHop, hop_amount: u64| -> Result<RouteHopQuote> {
+            let pool = &self.pools[hop.pool_index];
+            let sqrt_price_limit = if hop.a_to_b {
+                MIN_SQRT_PRICE_X64
+            } else {
+                MAX_SQRT_PRICE_X64
+            };
+            let quote = swap_quote(
+                &pool.whirlpool,
+                &pool.swap_tick_arrays(hop.a_to_b),
+                hop_amount,
+                sqrt_price_limit,
+                amount_specified_is_input,
+                hop.a_to_b,
+                timestamp,
+            )?;
+            Ok(RouteHopQuote {
+                whirlpool: pool.address,
+                a_to_b: hop.a_to_b,
+                sqrt_price_limit,
+                quote,
+            })
+        };
+
+        let mut hops = Vec::with_capacity(path.hops.len());
+        let mut hop_amount = amount;
+        if amount_specified_is_input {
+            for hop in path.hops.iter() {
+                let hop_quote = quote_hop(hop, hop_amount)?;
+                hop_amount = hop_quote.quote.amount_out;
+                hops.push(hop_quote);
+            }
+        } else {
+            for hop in path.hops.iter().rev() {
+                let hop_quote = quote_hop(hop, hop_amount)?;
+                hop_amount = hop_quote.quote.amount_in;
+                hops.push(hop_quote);
+            }
+            hops.reverse();
+        }
+
+        for pair in hops.windows(2) {
+            if pair[0].quote.amount_out != pair[1].quote.amount_in {
+                return Err(ErrorCode::IntermediateTokenAmountMismatch.into());
+            }
+        }
+
+        let amount_in = hops[0].quote.amount_in;
+        let amount_out = hops[hops.len() - 1].quote.amount_out;
+        // An exact amount that cannot be filled completely is not a usable route
+        let filled = if amount_specified_is_input {
+            amount_in == amount
+        } else {
+            amount_out == amount
+        };
+        if !filled {
+            return Err(ErrorCode::IntermediateTokenAmountMismatch.into());
+        }
+
+        Ok(RouteQuote {
+            amount_in,
+            amount_out,
+            amount_specified_is_input,
+            hops,
+        })
+    }
+
+    /// Find the route from `input_mint` to `output_mint` with the most output for exact-in
+    /// swaps, or the least input for exact-out swaps. Paths that cannot be quoted are skipped.
+    /// Returns None if no path can fill `amount`.
+    pub fn find_best_route(
+        &self,
+        input_mint: &Pubkey,
+        output_mint: &Pubkey,
+        amount: u64,
+        amount_specified_is_input: bool,
+        timestamp: u64,
+    ) -> Option<RouteQuote> {
+        self.get_paths(input_mint, output_mint)
+            .iter()
+            .filter_map(|path| {
+                self.quote_path(path, amount, amount_specified_is_input, timestamp)
+                    .ok()
+            })
+            .reduce(|best, quote| {
+                if is_better_quote(&quote, &best) {
+                    quote
+                } else {
+                    best
+                }
+            })
+    }
+
+    /// Split `amount` into `split_count` equal parts and greedily assign each part to the path
+    /// that improves the total the most. Paths sharing a pool with an already used path are
+    /// not combined, since their quotes would not account for each other's price impact.
+    /// Returns one quote per used path, or None if the amount cannot be filled.
+    // `Option::is_none_or` would need Rust 1.82.
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn find_best_split_route(
+        &self,
+        input_mint: &Pubkey,
+        output_mint: &Pubkey,
+        amount: u64,
+        amount_specified_is_input: bool,
+        split_count: u64,
+        timestamp: u64,
+    ) -> Option<Vec<RouteQuote>> {
+        let paths = self.get_paths(input_mint, output_mint);
+        if paths.is_empty() || split_count == 0 {
+            return None;
+        }
+
+        let part = amount / split_count;
+        let mut allocations: Vec<Option<RouteQuote>> = vec![None; paths.len()];
+
+        for i in 0..split_count {
+            // The last part carries the remainder of the division
+            let part_amount = if i == split_count - 1 {
+                amount - part * (split_count - 1)
+            } else {
+                part
+            };
+            if part_amount == 0 {
+                continue;
+            }
+
+            let used_pools: HashSet<usize> = paths
+                .iter()
+                .zip(allocations.iter())
+                .filter(|(_, allocation)| allocation.is_some())
+                .flat_map(|(path, _)| path.hops.iter().map(|hop| hop.pool_index))
+                .collect();
+
+            let mut best: Option<(usize, RouteQuote, i128)> = None;
+            for (path_index, path) in paths.iter().enumerate() {
+                let current = allocations[path_index].as_ref();
+                if current.is_none()
+                    && path
+                        .hops
+                        .iter()
+                        .any(|hop| used_pools.contains(&hop.pool_index))
+                {
+                    continue;
+                }
+
+                let allocated = current.map_or(0, |quote| quote.amount());
+                let quote = match self.quote_path(
+                    path,
+                    allocated.saturating_add(part_amount),
+                    amount_specified_is_input,
+                    timestamp,
+                ) {
+                    Ok(quote) => quote,
+                    Err(_) => continue,
+                };
+
+                // Exact-in gains output, exact-out spends input; both are compared as a gain
+                let gain = if amount_specified_is_input {
+                    quote.amount_out as i128 - current.map_or(0, |q| q.amount_out) as i128
+                } else {
+                    current.map_or(0, |q| q.amount_in) as i128 - quote.amount_in as i128
+                };
+                if best
+                    .as_ref()
+                    .map_or(true, |(_, _, best_gain)| gain > *best_gain)
+                {
+                    best = Some((path_index, quote, gain));
+                }
+            }
+
+            let (path_index, quote, _) = best?;
+            allocations[path_index] = Some(quote);
+        }
+
+        Some(allocations.into_iter().flatten().collect())
+    }
+}
+
+fn is_better_quote(quote: &RouteQuote, other: &RouteQuote) -> bool {
+    if quote.amount_specified_is_input {
+        quote.amount_out > other.amount_out
+    } else {
+        quote.amount_in < other.amount_in
+    }
+}
+
+#[cfg(test)]
+mod router_tests {
+    use super::*;
+    use whirlpool::math::sqrt_price_from_tick_index;
+
+    const TICK_SPACING: u16 = 64;
+
+    fn mint(seed: u8) -> Pubkey {
+        Pubkey::new_from_array([seed; 32])
+    }
+
+    fn pool(address: u8, mint_a: u8, mint_b: u8, liquidity: u128) -> RoutePool {
+        let ticks_in_array = TICK_ARRAY_SIZE * TICK_SPACING as i32;
+        RoutePool {
+            address: mint(address),
+            whirlpool: Whirlpool {
+                tick_spacing: TICK_SPACING,
+                fee_rate: 3000,
+                liquidity,
+                sqrt_price: sqrt_price_from_tick_index(0),
+                tick_current_index: 0,
+                token_mint_a: mint(mint_a),
+                token_mint_b: mint(mint_b),
+                ..Default::default()
+            },
+            tick_arrays: (-2..2)
+                .map(|i| TickArray {
+                    start_tick_index: i * ticks_in_array,
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    fn test_graph() -> PoolGraph {
+        PoolGraph::new(vec![
+            // Direct pool between mint 1 and mint 2
+            pool(101, 1, 2, 1_000_000),
+            // Deeper two-hop route through mint 3
+            pool(102, 1, 3, 1_000_000_000),
+            pool(103, 2, 3, 1_000_000_000),
+            // Unrelated pool
+            pool(104, 4, 5, 1_000_000_000),
+        ])
+    }
+
+    #[test]
+    fn test_get_paths() {
+        let graph = test_graph();
+        let paths = graph.get_paths(&mint(1), &mint(2));
+        assert_eq!(
+            paths,
+            vec![
+                Path {
+                    hops: vec![PathHop {
+                        pool_index: 0,
+                        a_to_b: true
+                    }]
+                },
+                Path {
+                    hops: vec![
+                        PathHop {
+                            pool_index: 1,
+                            a_to_b: true
+                        },
+                        PathHop {
+                            pool_index: 2,
+                            a_to_b: false
+                        }
+                    ]
+                },
+            ]
+        );
+        assert!(graph.get_paths(&mint(1), &mint(4)).is_empty());
+        assert!(graph.get_paths(&mint(1), &mint(1)).is_empty());
+    }
+
+    #[test]
+    fn test_swap_tick_arrays() {
+        let pool = pool(101, 1, 2, 1);
+        let starts = |a_to_b| -> Vec<i32> {
+            pool.swap_tick_arrays(a_to_b)
+                .iter()
+                .map(|ta| ta.start_tick_index)
+                .collect()
+        };
+        assert_eq!(starts(true), vec![0, -5632, -11264]);
+        assert_eq!(starts(false), vec![0, 5632]);
+    }
+
+    #[test]
+    fn test_find_best_route_exact_in() {
+        let graph = test_graph();
+        let route = graph
+            .find_best_route(&mint(1), &mint(2), 100_000, true, 0)
+            .unwrap();
+
+        // The shallow direct pool loses to the deep two-hop route
+        assert_eq!(route.hops.len(), 2);
+        assert_eq!(route.amount_in, 100_000);
+        assert_eq!(
+            route.hops[0].quote.amount_out,
+            route.hops[1].quote.amount_in
+        );
+
+        let direct = graph
+            .quote_path(&graph.get_paths(&mint(1), &mint(2))[0], 100_000, true, 0)
+            .unwrap();
+        assert!(route.amount_out > direct.amount_out);
+
+        assert!(route.swap_params(100).is_none());
+        let params = route.two_hop_swap_params(100).unwrap();
+        assert_eq!(
+            params,
+            TwoHopSwapParams {
+                whirlpool_one: mint(102),
+                whirlpool_two: mint(103),
+                amount: 100_000,
+                other_amount_threshold: route.amount_out * 99 / 100,
+                amount_specified_is_input: true,
+                a_to_b_one: true,
+                a_to_b_two: false,
+                sqrt_price_limit_one: MIN_SQRT_PRICE_X64,
+                sqrt_price_limit_two: MAX_SQRT_PRICE_X64,
+            }
+        );
+    }
+
+    #[test]
+    fn test_find_best_route_exact_out() {
+        let graph = test_graph();
+        let route = graph
+            .find_best_route(&mint(2), &mint(1), 10_000, false, 0)
+            .unwrap();
+
+        assert_eq!(route.amount_out, 10_000);
+        assert_eq!(route.hops.len(), 2);
+        // Exact-out thresholds round up
+        let threshold = route.other_amount_threshold(50);
+        assert!(threshold as u128 * 10_000 >= route.amount_in as u128 * 10_050);
+        assert!(threshold > route.amount_in);
+    }
+
+    #[test]
+    fn test_find_best_route_single_hop() {
+        let graph = test_graph();
+        let route = graph
+            .find_best_route(&mint(4), &mint(5), 1_000, true, 0)
+            .unwrap();
+        let params = route.swap_params(0).unwrap();
+        assert_eq!(params.whirlpool, mint(104));
+        assert_eq!(params.other_amount_threshold, route.amount_out);
+        assert!(params.a_to_b);
+        assert!(graph
+            .find_best_route(&mint(1), &mint(4), 1_000, true, 0)
+            .is_none());
+    }
+
+    #[test]
+    fn test_find_best_split_route() {
+        let graph = PoolGraph::new(vec![
+            pool(101, 1, 2, 1_000_000),
+            pool(102, 1, 3, 1_000_000),
+            pool(103, 3, 2, 1_000_000_000),
+        ]);
+        let amount = 200_000;
+        let routes = graph
+            .find_best_split_route(&mint(1), &mint(2), amount, true, 4, 0)
+            .unwrap();
+
+        let total_in: u64 = routes.iter().map(|route| route.amount_in).sum();
+        let total_out: u64 = routes.iter().map(|route| route.amount_out).sum();
+        assert_eq!(total_in, amount);
+        assert_eq!(routes.len(), 2);
+
+        let best = graph
+            .find_best_route(&mint(1), &mint(2), amount, true, 0)
+            .unwrap();
+        assert!(total_out > best.amount_out);
+    }
+}
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use whirlpool::state::{MAX_TICK_INDEX, MIN_TICK_INDEX, TICK_ARRAY_SIZE};
+
+use crate::{find_tick_array_address, MAX_SWAP_TICK_ARRAYS};
+
+/// Get the start tick index of the tick array containing `tick_index`, moved by `offset`
+/// tick arrays. Returns None if the resulting tick array is outside of the valid tick range.
+pub fn get_start_tick_index(tick_index: i32, tick_spacing: u16, offset: i32) -> Option<i32> {
+    let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+    let real_index = tick_index.div_euclid(ticks_in_array);
+    let start_tick_index = (real_index + offset) * ticks_in_array;
+
+    let min_tick_index = MIN_TICK_INDEX - ((MIN_TICK_INDEX % ticks_in_array) + ticks_in_array);
+    if start_tick_index < min_tick_index || start_tick_index > MAX_TICK_INDEX {
+        return None;
+    }
+    Some(start_tick_index)
+}
+
+/// Get the start tick indexes of the tick arrays a swap will traverse, in the order the swap
+/// instruction expects them. Fewer than MAX_SWAP_TICK_ARRAYS are returned when the swap
+/// reaches the end of the valid tick range.
+///
+/// For b_to_a swaps the search range of a tick array is shifted left by one tick spacing
+/// (see `TickArray::in_search_range`), so a current tick in the last tick spacing of an
+/// array starts the sequence at the next array.
+pub fn get_swap_tick_array_start_indexes(
+    tick_current_index: i32,
+    tick_spacing: u16,
+    a_to_b: bool,
+) -> Vec<i32> {
+    let shift = if a_to_b { 0 } else { tick_spacing as i32 };
+    let step = if a_to_b { -1 } else { 1 };
+
+    (0..MAX_SWAP_TICK_ARRAYS as i32)
+        .map_while(|i| get_start_tick_index(tick_current_index + shift, tick_spacing, i * step))
+        .collect()
+}
+
+/// Get the addresses of the tick arrays a swap will traverse, ready to be passed as
+/// `tick_array_0..2`. When fewer than three arrays exist in the swap direction, the last
+/// address is repeated, the swap instruction ignores repeated tick arrays.
+pub fn get_swap_tick_array_addresses(
+    whirlpool: &Pubkey,
+    tick_current_index: i32,
+    tick_spacing: u16,
+    a_to_b: bool,
+) -> [Pubkey; MAX_SWAP_TICK_ARRAYS] {
+    let addresses: Vec<Pubkey> =
+        get_swap_tick_array_start_indexes(tick_current_index, tick_spacing, a_to_b)
+            .into_iter()
+            .map(|start_tick_index| find_tick_array_address(whirlpool, start_tick_index).0)
+            .collect();
+
+    std::array::from_fn(|i| addresses[i.min(addresses.len() - 1)])
+}
+
+#[cfg(test)]
+mod tick_array_tests {
+    use super::*;
+    use whirlpool::state::TickArray;
+
+    #[test]
+    fn test_get_start_tick_index() {
+        assert_eq!(get_start_tick_index(0, 64, 0), Some(0));
+        assert_eq!(get_start_tick_index(5631, 64, 0), Some(0));
+        assert_eq!(get_start_tick_index(5632, 64, 0), Some(5632));
+        assert_eq!(get_start_tick_index(-1, 64, 0), Some(-5632));
+        assert_eq!(get_start_tick_index(-5632, 64, 0), Some(-5632));
+        assert_eq!(get_start_tick_index(0, 64, -2), Some(-11264));
+        assert_eq!(get_start_tick_index(0, 1, 1), Some(88));
+    }
+
+    #[test]
+    fn test_get_start_tick_index_out_of_range() {
+        assert_eq!(get_start_tick_index(MAX_TICK_INDEX, 64, 0), Some(439296));
+        assert_eq!(get_start_tick_index(MAX_TICK_INDEX, 64, 1), None);
+        assert_eq!(get_start_tick_index(MIN_TICK_INDEX, 64, 0), Some(-444928));
+        assert_eq!(get_start_tick_index(MIN_TICK_INDEX, 64, -1), None);
+    }
+
+    #[test]
+    fn test_swap_start_indexes() {
+        assert_eq!(
+            get_swap_tick_array_start_indexes(100, 64, true),
+            vec![0, -5632, -11264]
+        );
+        assert_eq!(
+            get_swap_tick_array_start_indexes(100, 64, false),
+            vec![0, 5632, 11264]
+        );
+    }
+
+    #[test]
+    fn test_swap_start_indexes_shifted_at_array_boundary() {
+        // The last tick spacing of an array belongs to the next array's search range for b_to_a
+        assert_eq!(
+            get_swap_tick_array_start_indexes(5570, 64, false),
+            vec![5632, 11264, 16896]
+        );
+        assert_eq!(
+            get_swap_tick_array_start_indexes(5570, 64, true),
+            vec![0, -5632, -11264]
+        );
+        // A current tick just below the array start is still searched from the array below for a_to_b
+        assert_eq!(
+            get_swap_tick_array_start_indexes(-1, 64, true),
+            vec![-5632, -11264, -16896]
+        );
+        assert_eq!(
+            get_swap_tick_array_start_indexes(-1, 64, false),
+            vec![0, 5632, 11264]
+        );
+    }
+
+    #[test]
+    fn test_swap_start_indexes_in_search_range() {
+        for &(tick_current_index, a_to_b) in &[
+            (0, true),
+            (0, false),
+            (-64, false),
+            (-65, false),
+            (5567, false),
+            (5568, false),
+            (-5633, true),
+        ] {
+            let start_indexes = get_swap_tick_array_start_indexes(tick_current_index, 64, a_to_b);
+            let first = TickArray {
+                start_tick_index: start_indexes[0],
+                ..Default::default()
+            };
+            assert!(first.in_search_range(tick_current_index, 64, !a_to_b));
+        }
+    }
+
+    #[test]
+    fn test_swap_start_indexes_at_tick_range_end() {
+        assert_eq!(
+            get_swap_tick_array_start_indexes(MAX_TICK_INDEX, 64, false),
+            vec![439296]
+        );
+        assert_eq!(
+            get_swap_tick_array_start_indexes(MIN_TICK_INDEX, 64, true),
+            vec![-444928]
+        );
+    }
+
+    #[test]
+    fn test_swap_addresses_pad_with_last_array() {
+        let whirlpool = Pubkey::new_unique();
+        let addresses = get_swap_tick_array_addresses(&whirlpool, MAX_TICK_INDEX - 5632, 64, false);
+        assert_eq!(addresses[0], find_tick_array_address(&whirlpool, 433664).0);
+        assert_eq!(addresses[1], find_tick_array_address(&whirlpool, 439296).0);
+        assert_eq!(addresses[2], addresses[1]);
+    }
+}